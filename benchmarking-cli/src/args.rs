@@ -0,0 +1,140 @@
+use crate::error::CliError;
+use crate::utils_examples;
+use clap::{ArgGroup, Args};
+use miden_air::ExecutionOptions;
+use std::path::PathBuf;
+
+// TARGET ARGUMENTS
+// ================================================================================================
+
+/// Command line arguments selecting a single program, either a bundled example or a program file.
+#[derive(Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["example", "program"])
+))]
+pub struct TargetArgs {
+    #[arg(short, long, help("Provide example name as in the examples directory"))]
+    pub example: Option<String>,
+
+    #[arg(short, long, help("Path to a Miden assembly program"))]
+    pub program: Option<PathBuf>,
+
+    #[arg(
+        long,
+        requires("program"),
+        help("Path to the inputs of the program, defaults to the .inputs file next to it")
+    )]
+    pub inputs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Directory containing the examples, defaults to the bundled examples"),
+        default_value(utils_examples::EXAMPLES_DIR),
+        hide_default_value(true)
+    )]
+    pub examples_dir: PathBuf,
+}
+
+/// A program selected on the command line, read into memory.
+pub struct Target {
+    /// Name of the example, or the file stem of the program.
+    pub name: String,
+    /// Miden assembly source of the program.
+    pub program: String,
+    /// Content of the inputs file of the program, empty if there is none.
+    pub inputs: String,
+    /// Expected stack output from the `.outputs` file next to the program, if there is one.
+    pub expected_output: Option<Vec<u64>>,
+}
+
+impl TargetArgs {
+    /// Returns the paths of the selected program and its inputs.
+    pub fn paths(&self) -> (PathBuf, PathBuf) {
+        match (&self.program, &self.example) {
+            (Some(program), _) => {
+                let inputs = self
+                    .inputs
+                    .clone()
+                    .unwrap_or_else(|| program.with_extension("inputs"));
+                (program.clone(), inputs)
+            }
+            (None, example) => {
+                let example = example.as_deref().unwrap_or_default();
+                (
+                    self.examples_dir.join(format!("{example}.masm")),
+                    self.examples_dir.join(format!("{example}.inputs")),
+                )
+            }
+        }
+    }
+
+    /// Reads the selected program and its inputs.
+    pub fn read(&self) -> Result<Target, CliError> {
+        let (program_path, inputs_path) = self.paths();
+        let (program, inputs) = utils_examples::read_program_files(&program_path, &inputs_path)
+            .map_err(CliError::Io)?;
+        let name = match &self.example {
+            Some(example) => example.clone(),
+            None => program_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| program_path.display().to_string()),
+        };
+        let expected_output =
+            utils_examples::read_expected_output(&program_path.with_extension("outputs"))
+                .map_err(CliError::Input)?;
+        Ok(Target {
+            name,
+            program,
+            inputs,
+            expected_output,
+        })
+    }
+}
+
+// EXECUTION ARGUMENTS
+// ================================================================================================
+
+/// Command line arguments controlling how programs are compiled and executed.
+#[derive(Args)]
+pub struct ExecutionArgs {
+    #[arg(
+        long,
+        help("Abort execution after this many cycles [default: no limit]")
+    )]
+    pub max_cycles: Option<u32>,
+
+    #[arg(
+        long,
+        help("Number of cycles the program is expected to run, used to pre-allocate the trace"),
+        default_value("64")
+    )]
+    pub expected_cycles: u32,
+
+    #[arg(
+        long,
+        help("Compile the programs with debug decorators and execute them in debug mode")
+    )]
+    pub debug: bool,
+
+    #[arg(
+        long,
+        help("Execute the programs in tracing mode, passing their trace decorators to the host")
+    )]
+    pub trace: bool,
+}
+
+impl ExecutionArgs {
+    /// Returns the execution options described by these arguments.
+    pub fn execution_options(&self) -> Result<ExecutionOptions, CliError> {
+        ExecutionOptions::new(
+            self.max_cycles,
+            self.expected_cycles,
+            self.trace,
+            self.debug,
+        )
+        .map_err(|err| CliError::Options(format!("Invalid execution options - {err}")))
+    }
+}
@@ -0,0 +1,302 @@
+use crate::error::CliError;
+use crate::memory;
+use crate::proof_breakdown::ProofBreakdown;
+use crate::proving_options::ProofParams;
+use crate::stats::PhaseStats;
+use crate::trace_breakdown::TraceBreakdown;
+use crate::utils_examples::check_stack_output;
+use crate::utils_proof::{self, SavedProof};
+use miden_air::ExecutionOptions;
+use miden_examples_core::{Inputs, MidenProgram};
+use std::time::{Duration, Instant};
+
+// BENCHMARK OPTIONS
+// ================================================================================================
+
+/// Options which are shared by all examples benchmarked in a single invocation of the CLI.
+pub struct BenchmarkOptions {
+    /// Parameters of the generated STARK proofs.
+    pub proof_params: ProofParams,
+    /// Conjectured security level in bits below which the generated proofs fail verification.
+    pub min_security: u32,
+    /// Cycle limits applied when executing and proving the program.
+    pub execution_options: ExecutionOptions,
+    /// Compile the program with debug decorators.
+    pub debug: bool,
+    /// Number of stack outputs which are reported as the result of the program.
+    pub num_outputs: usize,
+    /// Number of measured iterations of the whole pipeline, at least one.
+    pub iterations: usize,
+    /// Number of iterations which are run before measuring and whose timings are discarded.
+    pub warmup: usize,
+    /// Keep the proof generated by the last measured iteration.
+    pub keep_proof: bool,
+}
+
+// BENCHMARK RESULT
+// ================================================================================================
+
+/// Metrics collected while compiling, executing, proving and verifying a single example over all
+/// measured iterations. Every phase records its timings and its peak heap usage.
+#[derive(Default)]
+pub struct BenchmarkResult {
+    pub compilation_time: PhaseStats,
+    pub hot_compilation_time: PhaseStats,
+    pub execution_time: PhaseStats,
+    pub trace_len: usize,
+    pub trace_breakdown: TraceBreakdown,
+    pub proving_time: PhaseStats,
+    pub verification_time: PhaseStats,
+    pub proof_size: usize,
+    pub proof_breakdown: ProofBreakdown,
+    pub security_level: u32,
+    pub proof_params: ProofParams,
+    pub stack_output: Vec<u64>,
+    /// Proof of the last measured iteration, if [BenchmarkOptions::keep_proof] is set.
+    pub proof: Option<SavedProof>,
+}
+
+impl BenchmarkResult {
+    /// Records the timings and results of a single measured iteration.
+    fn push(&mut self, iteration: IterationResult) {
+        let phases = [
+            (&mut self.compilation_time, iteration.compilation),
+            (&mut self.hot_compilation_time, iteration.hot_compilation),
+            (&mut self.execution_time, iteration.execution),
+            (&mut self.proving_time, iteration.proving),
+            (&mut self.verification_time, iteration.verification),
+        ];
+        for (stats, sample) in phases {
+            stats.push(sample.duration);
+            stats.record_peak_heap(sample.peak_heap);
+        }
+        self.trace_len = iteration.trace_len;
+        self.trace_breakdown = iteration.trace_breakdown;
+        self.proof_size = iteration.proof_breakdown.total;
+        self.proof_breakdown = iteration.proof_breakdown;
+        self.security_level = iteration.security_level;
+        self.stack_output = iteration.stack_output;
+        self.proof = iteration.proof;
+    }
+}
+
+/// Metrics collected by a single run of the compile/execute/prove/verify pipeline.
+struct IterationResult {
+    compilation: PhaseSample,
+    hot_compilation: PhaseSample,
+    execution: PhaseSample,
+    trace_len: usize,
+    trace_breakdown: TraceBreakdown,
+    proving: PhaseSample,
+    verification: PhaseSample,
+    proof_breakdown: ProofBreakdown,
+    security_level: u32,
+    stack_output: Vec<u64>,
+    proof: Option<SavedProof>,
+}
+
+/// Duration and peak heap usage of a single phase of one iteration.
+struct PhaseSample {
+    duration: Duration,
+    peak_heap: usize,
+}
+
+impl PhaseSample {
+    /// Starts measuring a phase.
+    fn start() -> Instant {
+        memory::reset_peak();
+        Instant::now()
+    }
+
+    /// Finishes measuring the phase started at `start`.
+    fn finish(start: Instant) -> Self {
+        Self {
+            duration: start.elapsed(),
+            peak_heap: memory::peak(),
+        }
+    }
+}
+
+// BENCHMARK
+// ================================================================================================
+
+/// Compiles, executes, proves and verifies the provided program `options.warmup` times without
+/// measuring and then `options.iterations` times, and returns the metrics collected over the
+/// measured iterations.
+///
+/// `program_string` is the Miden assembly source and `input_string` the content of the
+/// corresponding `.inputs` file. If `expected_output` is provided, the benchmark fails if the
+/// program's stack output differs from it.
+pub fn benchmark_program(
+    program_string: &str,
+    input_string: &str,
+    expected_output: Option<&[u64]>,
+    options: &BenchmarkOptions,
+) -> Result<BenchmarkResult, CliError> {
+    for _ in 0..options.warmup {
+        run_iteration(program_string, input_string, expected_output, options)?;
+    }
+
+    let mut result = BenchmarkResult {
+        proof_params: options.proof_params.clone(),
+        ..Default::default()
+    };
+    for _ in 0..options.iterations {
+        result.push(run_iteration(
+            program_string,
+            input_string,
+            expected_output,
+            options,
+        )?);
+    }
+
+    Ok(result)
+}
+
+/// Runs the compile/execute/prove/verify pipeline once and measures each phase.
+fn run_iteration(
+    program_string: &str,
+    input_string: &str,
+    expected_output: Option<&[u64]>,
+    options: &BenchmarkOptions,
+) -> Result<IterationResult, CliError> {
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(input_string)
+        .map_err(|err| CliError::Input(format!("Failed to deserialize inputs - {err}")))?;
+
+    // Compilation time (cold)
+    let now = PhaseSample::start();
+    let mut program = MidenProgram::new(program_string, options.debug);
+    program
+        .compile_program()
+        .map_err(|err| CliError::Assembly(err.into()))?;
+    let compilation = PhaseSample::finish(now);
+
+    // Compilation time (hot), reusing the assembler which just compiled the same code
+    let now = PhaseSample::start();
+    program
+        .recompile_program()
+        .map_err(|err| CliError::Assembly(err.into()))?;
+    let hot_compilation = PhaseSample::finish(now);
+
+    let program_to_run = program.program.clone().expect("program was compiled");
+
+    let mut host =
+        MidenProgram::new_host(inputs.advice_provider.clone()).map_err(CliError::Execution)?;
+
+    // Execution time
+    let now = PhaseSample::start();
+    let trace = miden_vm::execute(
+        &program_to_run,
+        inputs.stack_inputs.clone(),
+        &mut host,
+        options.execution_options,
+    )
+    .map_err(|err| CliError::Execution(format!("Failed to generate execution trace - {err}")))?;
+    let execution = PhaseSample::finish(now);
+
+    if let Some(expected_output) = expected_output {
+        check_stack_output(expected_output, &trace.stack_outputs().as_int_vec())
+            .map_err(CliError::UnexpectedOutput)?;
+    }
+
+    // release the trace so that it does not count towards the memory used by proving
+    let trace_len = trace.get_trace_len();
+    let trace_breakdown = TraceBreakdown::new(&trace);
+    drop(trace);
+
+    // Proving time
+    let mut host = MidenProgram::new_host(inputs.advice_provider).map_err(CliError::Proving)?;
+
+    let now = PhaseSample::start();
+    let (output, proof) = miden_vm::prove(
+        &program_to_run,
+        inputs.stack_inputs.clone(),
+        &mut host,
+        options
+            .proof_params
+            .to_proving_options()
+            .with_execution_options(options.execution_options),
+    )
+    .map_err(|err| CliError::Proving(format!("Proving failed - {err}")))?;
+    let proving = PhaseSample::finish(now);
+
+    let proof_breakdown = ProofBreakdown::new(&proof);
+    let security_level = proof.security_level();
+
+    // Verification time
+    let program_info = program.program_info.expect("program was compiled");
+    let saved_proof = options.keep_proof.then(|| {
+        SavedProof::new(
+            proof.clone(),
+            program_info.program_hash(),
+            &output,
+            trace_breakdown.main_rows,
+        )
+    });
+
+    let now = PhaseSample::start();
+    utils_proof::verify_proof(
+        program_info,
+        inputs.stack_inputs,
+        output.clone(),
+        proof,
+        options.min_security,
+    )
+    .map_err(|err| CliError::Verification(format!("Program failed verification! - {err}")))?;
+    let verification = PhaseSample::finish(now);
+
+    Ok(IterationResult {
+        compilation,
+        hot_compilation,
+        execution,
+        trace_len,
+        trace_breakdown,
+        proving,
+        verification,
+        proof_breakdown,
+        security_level,
+        // We return the stack as defined by the user
+        stack_output: output
+            .stack_truncated(options.num_outputs)
+            .iter()
+            .map(|f| f.as_int())
+            .collect(),
+        proof: saved_proof,
+    })
+}
+
+#[test]
+fn test_benchmark_program_with_custom_params() {
+    use crate::proving_options::HashFunctionArg;
+    use crate::utils_proof::MIN_SECURITY_LEVEL;
+
+    // overridden parameters match none of the presets, so the proof must be verified with the
+    // parameters it was built with
+    let mut proof_params = ProofParams::with_96_bit_security(false);
+    proof_params.num_queries = 40;
+    proof_params.blowup_factor = 16;
+    proof_params.hash_fn = HashFunctionArg::Rpo256;
+
+    let options = BenchmarkOptions {
+        proof_params: proof_params.clone(),
+        min_security: MIN_SECURITY_LEVEL,
+        execution_options: ExecutionOptions::default(),
+        debug: false,
+        num_outputs: 1,
+        iterations: 1,
+        warmup: 0,
+        keep_proof: false,
+    };
+    let result = benchmark_program(
+        "begin push.1 push.2 add swap drop end",
+        "",
+        Some(&[3]),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(result.stack_output, vec![3]);
+    assert_eq!(result.proof_params, proof_params);
+    assert!(result.security_level >= MIN_SECURITY_LEVEL);
+}
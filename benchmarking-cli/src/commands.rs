@@ -0,0 +1,723 @@
+use crate::args::{ExecutionArgs, TargetArgs};
+use crate::benchmark::{benchmark_program, BenchmarkOptions, BenchmarkResult};
+use crate::compare::{self, Threshold};
+use crate::error::CliError;
+use crate::proving_options::ProvingArgs;
+use crate::report::{self, BenchmarkRecord, ExampleReport, OutputFormat};
+use crate::utils_examples::{self, check_stack_output};
+use crate::utils_proof::{self, SavedProof};
+use clap::builder::RangedU64ValueParser;
+use clap::{ArgGroup, Args};
+use miden_examples_core::{validate_inputs, Inputs, MerkleRoot, MidenProgram};
+use miden_vm::{Digest, Kernel, ProgramInfo};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+// RUN
+// ================================================================================================
+
+/// Arguments of the `run` command.
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    #[command(flatten)]
+    execution: ExecutionArgs,
+
+    #[arg(
+        short,
+        long,
+        help("Set the number of desired stack outputs"),
+        default_value("16")
+    )]
+    output: usize,
+}
+
+/// Executes a program without proving it and prints its outputs.
+pub fn run(args: &RunArgs) -> Result<(), CliError> {
+    let target = args.target.read()?;
+    let inputs = read_inputs(&target.inputs)?;
+    let program = compile(&target.program, args.execution.debug)?;
+    let mut host = MidenProgram::new_host(inputs.advice_provider).map_err(CliError::Execution)?;
+
+    let now = Instant::now();
+    let trace = miden_vm::execute(
+        program.program.as_ref().expect("program was compiled"),
+        inputs.stack_inputs,
+        &mut host,
+        args.execution.execution_options()?,
+    )
+    .map_err(|err| CliError::Execution(format!("Failed to generate execution trace - {err}")))?;
+    let execution_time = now.elapsed();
+
+    println!("Program hash: {}", trace.program_hash());
+    print_merkle_roots(&inputs.merkle_roots);
+    println!(
+        "Executed {} cycles in {} ms (trace length {})",
+        trace.trace_len_summary().main_trace_len(),
+        execution_time.as_millis(),
+        trace.get_trace_len()
+    );
+    println!(
+        "Stack output: {:?}",
+        trace.stack_outputs().stack_truncated(args.output)
+    );
+
+    if let Some(expected_output) = &target.expected_output {
+        check_stack_output(expected_output, &trace.stack_outputs().as_int_vec())
+            .map_err(CliError::UnexpectedOutput)?;
+        println!("Stack output matches the expected output");
+    }
+    Ok(())
+}
+
+// PROVE
+// ================================================================================================
+
+/// Arguments of the `prove` command.
+#[derive(Args)]
+pub struct ProveArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    #[command(flatten)]
+    execution: ExecutionArgs,
+
+    #[command(flatten)]
+    proving: ProvingArgs,
+
+    #[arg(long, help("Write the proof to this file [default: <NAME>.proof]"))]
+    proof: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help(
+            "Write the outputs of the program to this file [default: the proof path with an \
+             .outputs.json extension]"
+        )
+    )]
+    outputs: Option<PathBuf>,
+
+    #[arg(
+        short,
+        long,
+        help("Set the number of desired stack outputs"),
+        default_value("16")
+    )]
+    output: usize,
+}
+
+/// Executes and proves a program and writes the proof and the program outputs to files, so that
+/// they can be verified elsewhere with the `verify` command.
+pub fn prove(args: &ProveArgs) -> Result<(), CliError> {
+    let target = args.target.read()?;
+    let inputs = read_inputs(&target.inputs)?;
+    let program = compile(&target.program, args.execution.debug)?;
+    let program_to_prove = program.program.as_ref().expect("program was compiled");
+    let execution_options = args.execution.execution_options()?;
+    let proving_options = args
+        .proving
+        .proof_params()
+        .map_err(CliError::Options)?
+        .to_proving_options()
+        .with_execution_options(execution_options);
+
+    // the prover does not report the number of cycles, so the program is executed first
+    let mut host =
+        MidenProgram::new_host(inputs.advice_provider.clone()).map_err(CliError::Execution)?;
+    let cycles = miden_vm::execute(
+        program_to_prove,
+        inputs.stack_inputs.clone(),
+        &mut host,
+        execution_options,
+    )
+    .map_err(|err| CliError::Execution(format!("Failed to generate execution trace - {err}")))?
+    .trace_len_summary()
+    .main_trace_len();
+
+    let mut host = MidenProgram::new_host(inputs.advice_provider).map_err(CliError::Proving)?;
+    let now = Instant::now();
+    let (output, proof) = miden_vm::prove(
+        program_to_prove,
+        inputs.stack_inputs,
+        &mut host,
+        proving_options,
+    )
+    .map_err(|err| CliError::Proving(format!("Proving failed - {err}")))?;
+    let proving_time = now.elapsed();
+
+    if let Some(expected_output) = &target.expected_output {
+        check_stack_output(expected_output, &output.as_int_vec())
+            .map_err(CliError::UnexpectedOutput)?;
+    }
+
+    let proof_size = proof.to_bytes().len();
+    let security_level = proof.security_level();
+    let program_info = program.program_info.as_ref().expect("program was compiled");
+    let saved = SavedProof::new(proof, program_info.program_hash(), &output, cycles);
+
+    let proof_path = args
+        .proof
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("{}.proof", target.name)));
+    let outputs_path = args
+        .outputs
+        .clone()
+        .unwrap_or_else(|| utils_proof::outputs_path(&proof_path));
+    saved
+        .save(&proof_path, &outputs_path)
+        .map_err(CliError::Io)?;
+
+    println!("Program hash: {}", program_info.program_hash());
+    print_merkle_roots(&inputs.merkle_roots);
+    println!(
+        "Proved in {} ms, {proof_size} bytes ({security_level}-bit conjectured security)",
+        proving_time.as_millis(),
+    );
+    println!("Proof written to {}", proof_path.display());
+    println!("Outputs written to {}", outputs_path.display());
+    println!("Stack output: {:?}", output.stack_truncated(args.output));
+    Ok(())
+}
+
+// VERIFY
+// ================================================================================================
+
+/// Arguments of the `verify` command. The program is identified either by its source, from which
+/// its hash is computed, or by its hash alone.
+#[derive(Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["example", "program", "program_hash"])
+))]
+#[command(group(
+    ArgGroup::new("saved_proof")
+        .required(true)
+        .multiple(true)
+        .args(["proof", "outputs"])
+))]
+pub struct VerifyArgs {
+    #[arg(short, long, help("Provide example name as in the examples directory"))]
+    example: Option<String>,
+
+    #[arg(short, long, help("Path to a Miden assembly program"))]
+    program: Option<PathBuf>,
+
+    #[arg(long, help("Hash of the proven program as a hex string"))]
+    program_hash: Option<String>,
+
+    #[arg(
+        long,
+        help(
+            "Path to the inputs of the program, defaults to the .inputs file next to the \
+             program or to no inputs when only the program hash is given"
+        )
+    )]
+    inputs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Directory containing the examples, defaults to the bundled examples"),
+        default_value(utils_examples::EXAMPLES_DIR),
+        hide_default_value(true)
+    )]
+    examples_dir: PathBuf,
+
+    #[arg(
+        long,
+        help("Path to the proof, defaults to the proof embedded in the outputs")
+    )]
+    proof: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Path to the outputs, defaults to the .outputs.json file next to the proof")
+    )]
+    outputs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Reject proofs whose conjectured security is below this many bits"),
+        default_value_t = utils_proof::MIN_SECURITY_LEVEL
+    )]
+    min_security: u32,
+}
+
+/// Verifies a saved proof against the program hash, the inputs and the claimed outputs.
+pub fn verify(args: &VerifyArgs) -> Result<(), CliError> {
+    let (program_info, inputs) = match &args.program_hash {
+        Some(program_hash) => {
+            let program_hash = Digest::try_from(program_hash.as_str()).map_err(|err| {
+                CliError::Options(format!("Invalid program hash `{program_hash}` - {err}"))
+            })?;
+            let inputs = match &args.inputs {
+                Some(path) => fs::read_to_string(path).map_err(|err| {
+                    CliError::Io(format!(
+                        "Failed to read inputs file {} - {err}",
+                        path.display()
+                    ))
+                })?,
+                None => String::new(),
+            };
+            (ProgramInfo::new(program_hash, Kernel::default()), inputs)
+        }
+        None => {
+            let target = TargetArgs {
+                example: args.example.clone(),
+                program: args.program.clone(),
+                inputs: args.inputs.clone(),
+                examples_dir: args.examples_dir.clone(),
+            }
+            .read()?;
+            let program = compile(&target.program, false)?;
+            (
+                program.program_info.expect("program was compiled"),
+                target.inputs,
+            )
+        }
+    };
+
+    let mut inputs = read_inputs(&inputs)?;
+    let saved = SavedProof::load(args.proof.as_deref(), args.outputs.as_deref())
+        .map_err(CliError::Input)?;
+    inputs.stack_outputs = saved
+        .outputs
+        .stack_outputs()
+        .map_err(|err| CliError::Input(format!("Failed to deserialize outputs - {err}")))?;
+
+    // outputs written by older versions may not name the program they belong to
+    let program_hash = *program_info.program_hash();
+    if !saved.outputs.program_hash.is_empty()
+        && saved.outputs.program_hash != program_hash.to_string()
+    {
+        return Err(CliError::Verification(format!(
+            "The proof was generated for program {}, not for program {program_hash}",
+            saved.outputs.program_hash
+        )));
+    }
+
+    let now = Instant::now();
+    let security_level = utils_proof::verify_proof(
+        program_info,
+        inputs.stack_inputs,
+        inputs.stack_outputs,
+        saved.proof,
+        args.min_security,
+    )
+    .map_err(|err| CliError::Verification(format!("Program failed verification! - {err}")))?;
+
+    println!(
+        "Verified program {program_hash} in {} ms ({security_level}-bit conjectured security)",
+        now.elapsed().as_millis()
+    );
+    Ok(())
+}
+
+// VALIDATE
+// ================================================================================================
+
+/// Arguments of the `validate` command.
+#[derive(Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["example", "all", "inputs"])
+))]
+pub struct ValidateArgs {
+    #[arg(short, long, help("Provide example name as in the examples directory"))]
+    example: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        help("Validate the inputs of every example in the examples directory")
+    )]
+    all: bool,
+
+    #[arg(long, help("Path to an inputs file"))]
+    inputs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Directory containing the examples, defaults to the bundled examples"),
+        default_value(utils_examples::EXAMPLES_DIR),
+        hide_default_value(true)
+    )]
+    examples_dir: PathBuf,
+}
+
+/// Validates inputs files and prints every invalid value together with its JSON path. A missing
+/// inputs file of an example is valid, as it is treated as empty inputs.
+pub fn validate(args: &ValidateArgs) -> Result<(), CliError> {
+    // each inputs file is reported by the name of its example, or by its path
+    let example_inputs = |example: String| {
+        let path = args.examples_dir.join(format!("{example}.inputs"));
+        (example, path)
+    };
+    let files: Vec<(String, PathBuf)> = match (&args.inputs, &args.example) {
+        (Some(inputs), _) => vec![(inputs.display().to_string(), inputs.clone())],
+        (None, Some(example)) => vec![example_inputs(example.clone())],
+        (None, None) => utils_examples::discover_examples(&args.examples_dir, None)
+            .map_err(CliError::Io)?
+            .into_iter()
+            .map(example_inputs)
+            .filter(|(_, path)| path.exists())
+            .collect(),
+    };
+
+    let mut num_invalid = 0;
+    for (name, path) in &files {
+        let inputs = fs::read_to_string(path).map_err(|err| {
+            CliError::Io(format!("Failed to read inputs {} - {err}", path.display()))
+        })?;
+        let errors = validate_inputs(&inputs);
+        if errors.is_empty() {
+            println!("{name}: valid");
+        } else {
+            num_invalid += 1;
+            for err in errors {
+                println!("{name}: {err}");
+            }
+        }
+    }
+
+    if num_invalid > 0 {
+        return Err(CliError::Input(format!(
+            "{num_invalid} of {} inputs files are invalid",
+            files.len()
+        )));
+    }
+    Ok(())
+}
+
+// BENCH
+// ================================================================================================
+
+/// Arguments of the `bench` command.
+#[derive(Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["example", "all", "filter", "program"])
+))]
+pub struct BenchArgs {
+    #[arg(short, long, help("Provide example name as in the examples directory"))]
+    example: Option<String>,
+
+    #[arg(short, long, help("Benchmark every example in the examples directory"))]
+    all: bool,
+
+    #[arg(
+        short,
+        long,
+        help("Benchmark every example matching the glob pattern, e.g. 'merkle*'")
+    )]
+    filter: Option<String>,
+
+    #[arg(short, long, help("Path to a Miden assembly program to benchmark"))]
+    program: Option<PathBuf>,
+
+    #[arg(
+        long,
+        requires("program"),
+        help("Path to the inputs of the program, defaults to the .inputs file next to it")
+    )]
+    inputs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Directory containing the examples, defaults to the bundled examples"),
+        default_value(utils_examples::EXAMPLES_DIR),
+        hide_default_value(true)
+    )]
+    examples_dir: PathBuf,
+
+    #[command(flatten)]
+    proving: ProvingArgs,
+
+    #[arg(
+        long,
+        help("Reject proofs whose conjectured security is below this many bits"),
+        default_value_t = utils_proof::MIN_SECURITY_LEVEL
+    )]
+    min_security: u32,
+
+    #[command(flatten)]
+    execution: ExecutionArgs,
+
+    #[arg(
+        short,
+        long,
+        help("Set the number of desired stack outputs"),
+        default_value("1")
+    )]
+    output: usize,
+
+    #[arg(
+        short,
+        long,
+        help("Set the number of measured iterations per example"),
+        default_value("1"),
+        value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+    )]
+    iterations: usize,
+
+    #[arg(
+        short,
+        long,
+        help("Set the number of unmeasured warmup iterations per example"),
+        default_value("0")
+    )]
+    warmup: usize,
+
+    #[arg(
+        long,
+        value_enum,
+        help("Set the format of the benchmark report"),
+        default_value("text")
+    )]
+    format: OutputFormat,
+
+    #[arg(long, help("Write the benchmark report to a file instead of stdout"))]
+    out: Option<PathBuf>,
+
+    #[arg(
+        long,
+        conflicts_with_all(["all", "filter"]),
+        help(
+            "Save the proof of the last iteration to this file, and its outputs to the \
+             .outputs.json file next to it"
+        )
+    )]
+    save_proof: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help("Save the results as a JSON baseline for later comparisons")
+    )]
+    save_baseline: Option<PathBuf>,
+
+    #[arg(long, help("Compare the results against a JSON baseline"))]
+    compare: Option<PathBuf>,
+
+    #[arg(
+        long,
+        requires("compare"),
+        help(
+            "Fail if a metric increases by more than the given percentage, e.g. 'proving=10'. \
+             Defaults to 'proving=10' and 'trace_len=0'"
+        )
+    )]
+    threshold: Vec<Threshold>,
+}
+
+/// Compiles, executes, proves and verifies the selected examples and reports the metrics of each
+/// phase:
+/// - cold compilation time, i.e. when the example is compiled with a new assembler
+/// - hot compilation time, i.e. when the example is compiled with an assembler which was already
+///   used to compile the same code
+/// - execution time
+/// - proving time
+/// - verification time
+pub fn bench(args: &BenchArgs) -> Result<(), CliError> {
+    // only the report itself goes to stdout if it should be machine readable
+    if args.format == OutputFormat::Text {
+        println!("============================================================");
+        println!("Benchmarking Miden examples");
+        println!("============================================================");
+    }
+
+    let options = BenchmarkOptions {
+        proof_params: args.proving.proof_params().map_err(CliError::Options)?,
+        min_security: args.min_security,
+        execution_options: args.execution.execution_options()?,
+        debug: args.execution.debug,
+        num_outputs: args.output,
+        iterations: args.iterations,
+        warmup: args.warmup,
+        keep_proof: args.save_proof.is_some(),
+    };
+
+    let examples_dir = args.examples_dir.as_path();
+
+    // a single program or example is benchmarked on its own, otherwise we benchmark the whole
+    // (filtered) suite and report all examples together
+    let reports = if let Some(program) = &args.program {
+        let inputs = args
+            .inputs
+            .clone()
+            .unwrap_or_else(|| program.with_extension("inputs"));
+        let result = benchmark_files(program, &inputs, &options)?;
+        let name = program
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| program.display().to_string());
+        vec![ExampleReport {
+            name,
+            outcome: Ok(result),
+        }]
+    } else if let Some(example) = &args.example {
+        let result = benchmark_example(examples_dir, example, &options)?;
+        vec![ExampleReport {
+            name: example.clone(),
+            outcome: Ok(result),
+        }]
+    } else {
+        let examples = utils_examples::discover_examples(examples_dir, args.filter.as_deref())
+            .map_err(CliError::Io)?;
+        if examples.is_empty() {
+            return Err(CliError::Options(
+                "No examples found matching the provided filter".into(),
+            ));
+        }
+
+        let mut reports = Vec::with_capacity(examples.len());
+        for name in examples {
+            eprintln!("Benchmarking {name}...");
+            let outcome = benchmark_example(examples_dir, &name, &options);
+            reports.push(ExampleReport { name, outcome });
+        }
+        reports
+    };
+
+    if let Some(proof_path) = &args.save_proof {
+        let saved = reports
+            .iter()
+            .find_map(|report| report.outcome.as_ref().ok()?.proof.as_ref())
+            .expect("the proof is kept for a single example");
+        saved
+            .save(proof_path, &utils_proof::outputs_path(proof_path))
+            .map_err(CliError::Io)?;
+    }
+
+    let rendered = report::render(&reports, args.format).map_err(CliError::Io)?;
+    match &args.out {
+        Some(path) => fs::write(path, rendered).map_err(|err| {
+            CliError::Io(format!(
+                "Failed to write report to {} - {err}",
+                path.display()
+            ))
+        })?,
+        None => print!("{rendered}"),
+    }
+
+    let records: Vec<BenchmarkRecord> = reports.iter().map(BenchmarkRecord::from).collect();
+
+    if let Some(path) = &args.save_baseline {
+        let baseline = report::render(&reports, OutputFormat::Json).map_err(CliError::Io)?;
+        fs::write(path, baseline).map_err(|err| {
+            CliError::Io(format!(
+                "Failed to write baseline to {} - {err}",
+                path.display()
+            ))
+        })?;
+    }
+
+    if let Some(path) = &args.compare {
+        let baseline = compare::load_baseline(path).map_err(CliError::Input)?;
+        let thresholds = if args.threshold.is_empty() {
+            compare::DEFAULT_THRESHOLDS.to_vec()
+        } else {
+            args.threshold.clone()
+        };
+        let comparison = compare::compare(&baseline, &records, &thresholds);
+
+        // keep stdout clean if it carries a machine readable report
+        if args.format == OutputFormat::Text || args.out.is_some() {
+            print!(
+                "\nComparison with baseline {}\n{}",
+                path.display(),
+                comparison.report
+            );
+        } else {
+            eprint!(
+                "\nComparison with baseline {}\n{}",
+                path.display(),
+                comparison.report
+            );
+        }
+
+        if !comparison.regressions.is_empty() {
+            return Err(CliError::Regression(format!(
+                "Regressions against baseline: {}",
+                comparison.regressions.join("; ")
+            )));
+        }
+    }
+
+    let num_failed = reports
+        .iter()
+        .filter(|report| report.outcome.is_err())
+        .count();
+    if num_failed > 0 {
+        return Err(CliError::ExamplesFailed(format!(
+            "{num_failed} of {} examples failed",
+            reports.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Benchmarks the example `name` in `examples_dir`.
+fn benchmark_example(
+    examples_dir: &Path,
+    name: &str,
+    options: &BenchmarkOptions,
+) -> Result<BenchmarkResult, CliError> {
+    benchmark_files(
+        &examples_dir.join(format!("{name}.masm")),
+        &examples_dir.join(format!("{name}.inputs")),
+        options,
+    )
+}
+
+/// Reads the program and its inputs from the provided paths and benchmarks it.
+fn benchmark_files(
+    program: &Path,
+    inputs: &Path,
+    options: &BenchmarkOptions,
+) -> Result<BenchmarkResult, CliError> {
+    let (program_string, input_string) =
+        utils_examples::read_program_files(program, inputs).map_err(CliError::Io)?;
+    let expected_output = utils_examples::read_expected_output(&program.with_extension("outputs"))
+        .map_err(CliError::Input)?;
+    benchmark_program(
+        &program_string,
+        &input_string,
+        expected_output.as_deref(),
+        options,
+    )
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Deserializes the content of an inputs file.
+fn read_inputs(input_string: &str) -> Result<Inputs, CliError> {
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(input_string)
+        .map_err(|err| CliError::Input(format!("Failed to deserialize inputs - {err}")))?;
+    Ok(inputs)
+}
+
+/// Prints the roots of the merkle data of the inputs, which can be referenced as `root:<name>` in
+/// the inputs file.
+fn print_merkle_roots(merkle_roots: &[MerkleRoot]) {
+    for root in merkle_roots {
+        println!("Merkle root of {}: {}", root.name, root.root);
+    }
+}
+
+/// Compiles the provided Miden assembly source.
+fn compile(program_string: &str, debug: bool) -> Result<MidenProgram, CliError> {
+    let mut program = MidenProgram::new(program_string, debug);
+    program
+        .compile_program()
+        .map_err(|err| CliError::Assembly(err.into()))?;
+    Ok(program)
+}
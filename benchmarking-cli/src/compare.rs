@@ -0,0 +1,264 @@
+use crate::report::{BenchmarkRecord, PhaseSummary};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+// METRIC
+// ================================================================================================
+
+/// A metric of a benchmark record which can be compared against a baseline. Timings are compared
+/// by their median.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    CompilationCold,
+    CompilationHot,
+    Execution,
+    Proving,
+    Verification,
+    TraceLen,
+    ProofSize,
+}
+
+impl Metric {
+    /// All metrics in the order in which they are reported.
+    pub const ALL: [Metric; 7] = [
+        Metric::CompilationCold,
+        Metric::CompilationHot,
+        Metric::Execution,
+        Metric::Proving,
+        Metric::Verification,
+        Metric::TraceLen,
+        Metric::ProofSize,
+    ];
+
+    /// Returns the name of the metric, matching the field name in the JSON report.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::CompilationCold => "compilation_cold",
+            Metric::CompilationHot => "compilation_hot",
+            Metric::Execution => "execution",
+            Metric::Proving => "proving",
+            Metric::Verification => "verification",
+            Metric::TraceLen => "trace_len",
+            Metric::ProofSize => "proof_size",
+        }
+    }
+
+    /// Returns the value of this metric in the provided record, or `None` if the example failed.
+    fn value(&self, record: &BenchmarkRecord) -> Option<f64> {
+        let median =
+            |summary: &Option<PhaseSummary>| summary.as_ref().map(|summary| summary.median_ms);
+        match self {
+            Metric::CompilationCold => median(&record.compilation_cold),
+            Metric::CompilationHot => median(&record.compilation_hot),
+            Metric::Execution => median(&record.execution),
+            Metric::Proving => median(&record.proving),
+            Metric::Verification => median(&record.verification),
+            Metric::TraceLen => record.trace_len.map(|len| len as f64),
+            Metric::ProofSize => record.proof_size.map(|size| size as f64),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Metric::ALL
+            .into_iter()
+            .find(|metric| metric.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Metric::ALL.iter().map(Metric::name).collect();
+                format!(
+                    "unknown metric `{name}`, expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+// THRESHOLD
+// ================================================================================================
+
+/// Maximum allowed increase of a metric relative to the baseline, in percent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Threshold {
+    pub metric: Metric,
+    pub max_increase: f64,
+}
+
+/// Thresholds applied when no threshold is provided: proving may not get more than 10% slower and
+/// the trace length may not grow at all.
+pub const DEFAULT_THRESHOLDS: [Threshold; 2] = [
+    Threshold {
+        metric: Metric::Proving,
+        max_increase: 10.0,
+    },
+    Threshold {
+        metric: Metric::TraceLen,
+        max_increase: 0.0,
+    },
+];
+
+impl FromStr for Threshold {
+    type Err = String;
+
+    /// Parses a threshold of the form `<metric>=<percent>`, e.g. `proving=10`.
+    fn from_str(threshold: &str) -> Result<Self, Self::Err> {
+        let (metric, max_increase) = threshold
+            .split_once('=')
+            .ok_or_else(|| format!("expected `<metric>=<percent>`, got `{threshold}`"))?;
+        let max_increase = max_increase
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|err| format!("invalid percentage `{max_increase}` - {err}"))?;
+        Ok(Self {
+            metric: metric.parse()?,
+            max_increase,
+        })
+    }
+}
+
+// COMPARISON
+// ================================================================================================
+
+/// Result of comparing a benchmark run against a baseline.
+pub struct Comparison {
+    /// Human readable per-example deltas.
+    pub report: String,
+    /// Descriptions of all threshold violations.
+    pub regressions: Vec<String>,
+}
+
+/// Reads a baseline which was written with `--format json`.
+pub fn load_baseline(path: &Path) -> Result<Vec<BenchmarkRecord>, String> {
+    let baseline = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read baseline {} - {err}", path.display()))?;
+    serde_json::from_str(&baseline)
+        .map_err(|err| format!("Failed to parse baseline {} - {err}", path.display()))
+}
+
+/// Compares the current records against the baseline records of the same examples and checks the
+/// relative change of each metric against the thresholds. Examples missing from the baseline are
+/// reported but not checked.
+pub fn compare(
+    baseline: &[BenchmarkRecord],
+    current: &[BenchmarkRecord],
+    thresholds: &[Threshold],
+) -> Comparison {
+    let mut report = String::new();
+    let mut regressions = Vec::new();
+
+    for record in current {
+        writeln!(report, "{}:", record.example).unwrap();
+        let Some(base) = baseline.iter().find(|base| base.example == record.example) else {
+            writeln!(report, "  not present in baseline").unwrap();
+            continue;
+        };
+
+        for metric in Metric::ALL {
+            let (Some(before), Some(after)) = (metric.value(base), metric.value(record)) else {
+                writeln!(report, "  {:<18} n/a", metric.name()).unwrap();
+                continue;
+            };
+            let change = relative_change(before, after);
+
+            let violated = thresholds
+                .iter()
+                .filter(|threshold| threshold.metric == metric)
+                .any(|threshold| change > threshold.max_increase);
+            let marker = if violated { "  REGRESSION" } else { "" };
+            // timings are in milliseconds, all other metrics are counts
+            let precision = match metric {
+                Metric::TraceLen | Metric::ProofSize => 0,
+                _ => 2,
+            };
+            writeln!(
+                report,
+                "  {:<18} {before:>12.precision$} -> {after:>12.precision$} ({change:+.2}%){marker}",
+                metric.name(),
+            )
+            .unwrap();
+
+            if violated {
+                regressions.push(format!(
+                    "{}: {} increased by {change:.2}%",
+                    record.example,
+                    metric.name()
+                ));
+            }
+        }
+    }
+
+    Comparison {
+        report,
+        regressions,
+    }
+}
+
+/// Returns the change from `before` to `after` in percent of `before`.
+fn relative_change(before: f64, after: f64) -> f64 {
+    if before == 0.0 {
+        return if after == 0.0 { 0.0 } else { f64::INFINITY };
+    }
+    (after - before) / before * 100.0
+}
+
+#[test]
+fn test_compare() {
+    let record = |example: &str, trace_len: usize, proving_ms: f64| BenchmarkRecord {
+        example: example.to_string(),
+        security_level: Some(96),
+        trace_len: Some(trace_len),
+        trace_breakdown: None,
+        proof_size: Some(1000),
+        proof_breakdown: None,
+        proof_params: None,
+        compilation_cold: None,
+        compilation_hot: None,
+        execution: None,
+        proving: Some(PhaseSummary {
+            min_ms: proving_ms,
+            median_ms: proving_ms,
+            mean_ms: proving_ms,
+            stddev_ms: 0.0,
+            max_ms: proving_ms,
+            iterations: 1,
+            high_variance: false,
+            peak_heap_bytes: 0,
+        }),
+        verification: None,
+        stack_output: Some(vec![]),
+        error: None,
+    };
+
+    let baseline = [
+        record("fibonacci", 1024, 100.0),
+        record("catalan", 512, 100.0),
+    ];
+
+    // 5% slower proving and unchanged trace length are within the default thresholds
+    let current = [record("fibonacci", 1024, 105.0), record("nprime", 64, 1.0)];
+    let comparison = compare(&baseline, &current, &DEFAULT_THRESHOLDS);
+    assert!(comparison.regressions.is_empty());
+    assert!(comparison.report.contains("not present in baseline"));
+
+    // 20% slower proving and any growth of the trace length are not
+    let current = [
+        record("fibonacci", 1024, 120.0),
+        record("catalan", 1024, 100.0),
+    ];
+    let comparison = compare(&baseline, &current, &DEFAULT_THRESHOLDS);
+    assert_eq!(
+        comparison.regressions,
+        vec![
+            "fibonacci: proving increased by 20.00%".to_string(),
+            "catalan: trace_len increased by 100.00%".to_string(),
+        ]
+    );
+
+    let threshold: Threshold = "proving=25%".parse().unwrap();
+    let comparison = compare(&baseline, &current, &[threshold]);
+    assert!(comparison.regressions.is_empty());
+}
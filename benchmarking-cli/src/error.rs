@@ -0,0 +1,89 @@
+use std::fmt;
+
+// CLI ERROR
+// ================================================================================================
+
+/// Errors reported by the CLI commands. Every variant holds a readable message and maps to its
+/// own exit code, so that scripts can tell why an invocation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// A file could not be read or written.
+    Io(String),
+    /// The provided command line options are invalid.
+    Options(String),
+    /// An inputs, outputs, proof or baseline file could not be parsed.
+    Input(String),
+    /// The program could not be compiled.
+    Assembly(String),
+    /// The program failed during execution.
+    Execution(String),
+    /// The program could not be proven.
+    Proving(String),
+    /// The proof did not verify.
+    Verification(String),
+    /// The stack output of the program differs from the expected output.
+    UnexpectedOutput(String),
+    /// A metric regressed against the baseline.
+    Regression(String),
+    /// Some examples of a suite run failed, each with its own error in the report.
+    ExamplesFailed(String),
+}
+
+impl CliError {
+    /// Returns the exit code of the process for this error. Exit code 2 is left to clap, which
+    /// uses it for command line usage errors.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::ExamplesFailed(_) => 1,
+            Self::Io(_) => 3,
+            Self::Options(_) => 4,
+            Self::Input(_) => 5,
+            Self::Assembly(_) => 6,
+            Self::Execution(_) => 7,
+            Self::Proving(_) => 8,
+            Self::Verification(_) => 9,
+            Self::UnexpectedOutput(_) => 10,
+            Self::Regression(_) => 11,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(msg)
+            | Self::Options(msg)
+            | Self::Input(msg)
+            | Self::Assembly(msg)
+            | Self::Execution(msg)
+            | Self::Proving(msg)
+            | Self::Verification(msg)
+            | Self::UnexpectedOutput(msg)
+            | Self::Regression(msg)
+            | Self::ExamplesFailed(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+#[test]
+fn test_exit_codes() {
+    let errors = [
+        CliError::ExamplesFailed(String::new()),
+        CliError::Io(String::new()),
+        CliError::Options(String::new()),
+        CliError::Input(String::new()),
+        CliError::Assembly(String::new()),
+        CliError::Execution(String::new()),
+        CliError::Proving(String::new()),
+        CliError::Verification(String::new()),
+        CliError::UnexpectedOutput(String::new()),
+        CliError::Regression(String::new()),
+    ];
+    let mut codes: Vec<u8> = errors.iter().map(CliError::exit_code).collect();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), errors.len());
+    assert!(!codes.contains(&0) && !codes.contains(&2));
+}
@@ -1,10 +1,19 @@
-mod utils_input;
-mod utils_program;
-use clap::Parser;
-use miden_vm::{ProvingOptions, DefaultHost, MemAdviceProvider};
-use miden_air::ExecutionOptions;
-use std::fs;
-use std::time::Instant;
+mod args;
+mod benchmark;
+mod commands;
+mod compare;
+mod error;
+mod memory;
+mod proof_breakdown;
+mod proving_options;
+mod report;
+mod stats;
+mod trace_breakdown;
+mod utils_examples;
+mod utils_proof;
+use clap::{Parser, Subcommand};
+use commands::{BenchArgs, ProveArgs, RunArgs, ValidateArgs, VerifyArgs};
+use std::process::ExitCode;
 
 #[derive(Parser)]
 #[clap(
@@ -12,117 +21,72 @@ use std::time::Instant;
     version,
     about = "A very simple benchmarking CLI for Miden examples"
 )]
-
 struct Cli {
-    #[arg(
-        short,
-        long,
-        help("Provide example name as in ../examples"),
-        required(true)
-    )]
-    example: String,
-
-    #[arg(
-        short,
-        long,
-        help("Set to 'high' if 128-bit is needed"),
-        default_value("")
-    )]
-    security: String,
-
-    #[arg(
-        short,
-        long,
-        help("Set the number of desired stack outputs"),
-        default_value("1")
-    )]
-    output: usize,
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // The CLI should take a path to the example directory as input, execute/prove the example, and output the following metrics:
-
-    // Cold compilation time (i.e., when the example is compiled with a new assembler).
-    // Hot compilation time (i.e., when the example is compiled with an assembler which was already used to compile the same code).
-    // Execution time.
-    // Proving time.
-    // Verification time.
-
-    println!("============================================================");
-    println!("Benchmarking Miden examples");
-    println!("============================================================");
-
-    let args = Cli::parse();
-
-    // let's read the program
-    let program_string = fs::read_to_string(format!("../examples/{}.masm", &args.example))?;
-
-    let input_string = fs::read_to_string(format!("../examples/{}.inputs", &args.example))?;
-    let mut inputs = utils_input::Inputs::new();
-    inputs
-        .deserialize_inputs(input_string.as_str())
-        .map_err(|err| format!("Failed to deserialize inputs - {:?}", err))?;
-
-    // Compilation time
-    let now = Instant::now();
-    let mut program = utils_program::MidenProgram::new(program_string.as_str());
-    program
-        .compile_program()
-        .map_err(|err| format!("Failed to compile program - {:?}", err))?;
-
-    println! {"Compilation Time (cold): {} ms", now.elapsed().as_millis()}
-
-    let program_to_run = program.program.clone().unwrap();
-
-    let host = DefaultHost::new(MemAdviceProvider::from(inputs.advice_provider.clone()));
-    
-    let execution_options = ExecutionOptions::new(None, 64)
-    .map_err(|err| format!("{err}"))?;
-
-    // Execution time
-    let now = Instant::now();
-    let trace = miden_vm::execute(
-        &program_to_run,
-        inputs.stack_inputs.clone(),
-        host,
-        execution_options,
-    )
-    .map_err(|err| format!("Failed to generate exection trace = {:?}", err))
-    .unwrap();
+#[derive(Subcommand)]
+enum Command {
+    /// Execute a program and print its outputs
+    Run(RunArgs),
+    /// Execute and prove a program, writing the proof and its outputs to files
+    Prove(ProveArgs),
+    /// Verify a saved proof against a program (or its hash), its inputs and its outputs
+    Verify(VerifyArgs),
+    /// Validate inputs files and report every invalid value with its location
+    Validate(ValidateArgs),
+    /// Compile, execute, prove and verify examples and report the time spent in each phase
+    Bench(BenchArgs),
+}
 
-    println! {"Execution Time: {} steps in {} ms", trace.get_trace_len(), now.elapsed().as_millis()}
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    // Proving time
-    let proof_options = if args.security == "high" {
-        ProvingOptions::with_128_bit_security(false)
-    } else {
-        ProvingOptions::with_96_bit_security(false)
+    let result = match &cli.command {
+        Command::Run(args) => commands::run(args),
+        Command::Prove(args) => commands::prove(args),
+        Command::Verify(args) => commands::verify(args),
+        Command::Validate(args) => commands::validate(args),
+        Command::Bench(args) => commands::bench(args),
     };
 
-    let host = DefaultHost::new(MemAdviceProvider::from(inputs.advice_provider));
-
-    let now = Instant::now();
-    let (output, proof) = miden_vm::prove(
-        &program.program.unwrap(),
-        inputs.stack_inputs.clone(),
-        host,
-        proof_options,
-    )
-    .expect("Proving failed");
-
-    println! {"Proving Time: {} ms", now.elapsed().as_millis()}
-
-    // Verification time
-    let program_info = program.program_info.unwrap();
-
-    let now = Instant::now();
-    miden_vm::verify(program_info, inputs.stack_inputs, output.clone(), proof)
-        .map_err(|err| format!("Program failed verification! - {}", err))?;
-
-    println! {"Verification Time: {} ms", now.elapsed().as_millis()}
-
-    // We return the stack as defined by the user
-    println! {"Result: {:?}", output.stack_truncated(args.output)};
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
 
-    Ok(())
+#[test]
+fn test_cli() {
+    use clap::CommandFactory;
+    Cli::command().debug_assert();
+
+    // the program hash alone identifies the program to verify
+    assert!(Cli::try_parse_from([
+        "cli",
+        "verify",
+        "--program-hash",
+        "0x00",
+        "--proof",
+        "a.proof",
+        "--outputs",
+        "a.json"
+    ])
+    .is_ok());
+    assert!(
+        Cli::try_parse_from(["cli", "verify", "--proof", "a.proof", "--outputs", "a.json"])
+            .is_err()
+    );
+
+    // at least one iteration has to be measured
+    assert!(Cli::try_parse_from(["cli", "bench", "-e", "fibonacci", "--iterations", "1"]).is_ok());
+    assert!(Cli::try_parse_from(["cli", "bench", "-e", "fibonacci", "--iterations", "0"]).is_err());
+
+    // validate needs to know which inputs to validate
+    assert!(Cli::try_parse_from(["cli", "validate", "--all"]).is_ok());
+    assert!(Cli::try_parse_from(["cli", "validate"]).is_err());
 }
@@ -0,0 +1,90 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// PEAK HEAP TRACKING
+// ================================================================================================
+
+/// Number of bytes currently allocated on the heap.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Highest value of [ALLOCATED] since the last call to [reset_peak].
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocator which forwards to the system allocator and keeps track of the heap usage of the
+/// process, including all threads spawned by the prover.
+struct PeakAllocator;
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                ALLOCATED.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Records an allocation of `size` bytes and updates the peak.
+fn grow(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::SeqCst) + size;
+    PEAK.fetch_max(allocated, Ordering::SeqCst);
+}
+
+/// Starts a new measurement, resetting the peak to the amount of memory allocated right now.
+///
+/// The peak never drops below the memory which is currently allocated, even if other threads
+/// allocate while it is reset.
+pub fn reset_peak() {
+    PEAK.store(ALLOCATED.load(Ordering::SeqCst), Ordering::SeqCst);
+    // an allocation between the load and the store raised a peak which was just overwritten
+    PEAK.fetch_max(ALLOCATED.load(Ordering::SeqCst), Ordering::SeqCst);
+}
+
+/// Returns the highest number of bytes allocated on the heap since the last call to [reset_peak].
+pub fn peak() -> usize {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Formats a number of bytes in mebibytes.
+pub fn format_mib(bytes: usize) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[test]
+fn test_peak_heap() {
+    // other tests allocate and reset the peak concurrently, so only the live buffer is certainly
+    // accounted for
+    reset_peak();
+    let buffer = std::hint::black_box(vec![0u8; 1 << 20]);
+    assert!(peak() >= buffer.len());
+    reset_peak();
+    assert!(peak() >= buffer.len());
+}
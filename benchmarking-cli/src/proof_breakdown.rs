@@ -0,0 +1,98 @@
+use miden_vm::utils::Serializable;
+use miden_vm::ExecutionProof;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// PROOF BREAKDOWN
+// ================================================================================================
+
+/// Serialized size in bytes of each component of an execution proof. The components add up to the
+/// size of [ExecutionProof::to_bytes], which additionally holds one byte for the number of unique
+/// queries and one byte for the hash function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofBreakdown {
+    /// Trace layout, field modulus and proof options.
+    pub context: usize,
+    /// Commitments to the trace, the constraint evaluations and the FRI layers.
+    pub commitments: usize,
+    /// Queried trace rows together with their authentication paths.
+    pub trace_queries: usize,
+    /// Queried constraint evaluations together with their authentication paths.
+    pub constraint_queries: usize,
+    /// Out-of-domain evaluations of the trace and the constraints.
+    pub ood_frame: usize,
+    /// FRI layer queries and the FRI remainder.
+    pub fri_proof: usize,
+    /// Proof-of-work nonce found by grinding.
+    pub pow_nonce: usize,
+    /// Size of the whole serialized proof.
+    pub total: usize,
+}
+
+impl ProofBreakdown {
+    /// Measures the serialized size of each component of the provided proof.
+    pub fn new(proof: &ExecutionProof) -> Self {
+        let stark_proof = proof.stark_proof();
+        Self {
+            context: stark_proof.context.to_bytes().len(),
+            commitments: stark_proof.commitments.to_bytes().len(),
+            // the queries of each trace segment are serialized without the length of the vector
+            trace_queries: stark_proof
+                .trace_queries
+                .iter()
+                .map(|queries| queries.to_bytes().len())
+                .sum(),
+            constraint_queries: stark_proof.constraint_queries.to_bytes().len(),
+            ood_frame: stark_proof.ood_frame.to_bytes().len(),
+            fri_proof: stark_proof.fri_proof.to_bytes().len(),
+            pow_nonce: stark_proof.pow_nonce.to_le_bytes().len(),
+            total: proof.to_bytes().len(),
+        }
+    }
+}
+
+impl fmt::Display for ProofBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "context {} / commitments {} / trace queries {} / constraint queries {} / \
+             OOD frame {} / FRI {} / PoW nonce {} bytes",
+            self.context,
+            self.commitments,
+            self.trace_queries,
+            self.constraint_queries,
+            self.ood_frame,
+            self.fri_proof,
+            self.pow_nonce,
+        )
+    }
+}
+
+#[test]
+fn test_proof_breakdown() {
+    use miden_examples_core::{Inputs, MidenProgram};
+    use miden_vm::ProvingOptions;
+
+    let mut program = MidenProgram::new("begin push.1 push.2 add swap drop end", false);
+    program.compile_program().unwrap();
+    let inputs = Inputs::new();
+    let mut host = MidenProgram::new_host(inputs.advice_provider).unwrap();
+    let (_, proof) = miden_vm::prove(
+        &program.program.unwrap(),
+        inputs.stack_inputs,
+        &mut host,
+        ProvingOptions::with_96_bit_security(false),
+    )
+    .unwrap();
+
+    let breakdown = ProofBreakdown::new(&proof);
+    let components = breakdown.context
+        + breakdown.commitments
+        + breakdown.trace_queries
+        + breakdown.constraint_queries
+        + breakdown.ood_frame
+        + breakdown.fri_proof
+        + breakdown.pow_nonce;
+    assert_eq!(components + 2, breakdown.total);
+    assert_eq!(breakdown.total, proof.to_bytes().len());
+}
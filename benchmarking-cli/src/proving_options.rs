@@ -0,0 +1,291 @@
+use clap::{Args, ValueEnum};
+use miden_vm::{FieldExtension, HashFunction, ProvingOptions};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// LIMITS
+// ================================================================================================
+
+// these mirror the limits enforced by the STARK prover, which panics if they are violated
+
+const MAX_NUM_QUERIES: usize = 255;
+/// The constraints of the processor AIR need a blowup factor of at least 8.
+const MIN_BLOWUP_FACTOR: usize = 8;
+const MAX_BLOWUP_FACTOR: usize = 128;
+const MAX_GRINDING_FACTOR: u32 = 32;
+const MIN_FRI_FOLDING_FACTOR: usize = 2;
+const MAX_FRI_FOLDING_FACTOR: usize = 16;
+const MAX_FRI_REMAINDER_DEGREE: usize = 255;
+
+// PROVING ARGUMENTS
+// ================================================================================================
+
+/// Command line arguments controlling the STARK proof parameters. The parameters start from the
+/// preset selected by `--security`, and each explicitly provided argument overrides the preset.
+#[derive(Args)]
+pub struct ProvingArgs {
+    #[arg(
+        short,
+        long,
+        help("Set to 'high' if 128-bit is needed"),
+        default_value("")
+    )]
+    security: String,
+
+    #[arg(long, help("Number of queries of the STARK proof"))]
+    num_queries: Option<usize>,
+
+    #[arg(
+        long,
+        help("Blowup factor of the low-degree extension, a power of two")
+    )]
+    blowup_factor: Option<usize>,
+
+    #[arg(long, help("Number of proof-of-work bits (grinding factor)"))]
+    grinding_factor: Option<u32>,
+
+    #[arg(long, value_enum, help("Field extension used for composition and FRI"))]
+    field_extension: Option<FieldExtensionArg>,
+
+    #[arg(long, help("FRI folding factor, a power of two"))]
+    fri_folding_factor: Option<usize>,
+
+    #[arg(
+        long,
+        help("Maximum degree of the FRI remainder, one less than a power of two")
+    )]
+    fri_remainder_max_degree: Option<usize>,
+
+    #[arg(long, value_enum, help("Hash function used to build the proof"))]
+    hash_fn: Option<HashFunctionArg>,
+}
+
+impl ProvingArgs {
+    /// Resolves the proof parameters from the selected preset and the explicit overrides.
+    pub fn proof_params(&self) -> Result<ProofParams, String> {
+        let hash_fn = self.hash_fn;
+        let mut params = match self.security.as_str() {
+            "high" => ProofParams::with_128_bit_security(hash_fn == Some(HashFunctionArg::Rpo256)),
+            _ => ProofParams::with_96_bit_security(hash_fn == Some(HashFunctionArg::Rpo256)),
+        };
+
+        if let Some(num_queries) = self.num_queries {
+            params.num_queries = num_queries;
+        }
+        if let Some(blowup_factor) = self.blowup_factor {
+            params.blowup_factor = blowup_factor;
+        }
+        if let Some(grinding_factor) = self.grinding_factor {
+            params.grinding_factor = grinding_factor;
+        }
+        if let Some(field_extension) = self.field_extension {
+            params.field_extension = field_extension;
+        }
+        if let Some(fri_folding_factor) = self.fri_folding_factor {
+            params.fri_folding_factor = fri_folding_factor;
+        }
+        if let Some(fri_remainder_max_degree) = self.fri_remainder_max_degree {
+            params.fri_remainder_max_degree = fri_remainder_max_degree;
+        }
+        if let Some(hash_fn) = hash_fn {
+            params.hash_fn = hash_fn;
+        }
+
+        params.validate()?;
+        Ok(params)
+    }
+}
+
+/// Field extension of the STARK proof, see [FieldExtension].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldExtensionArg {
+    None,
+    Quadratic,
+    Cubic,
+}
+
+/// Hash function used to build the STARK proof, see [HashFunction].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum HashFunctionArg {
+    #[value(name = "blake3-192")]
+    #[serde(rename = "blake3-192")]
+    Blake3_192,
+    #[value(name = "blake3-256")]
+    #[serde(rename = "blake3-256")]
+    Blake3_256,
+    #[value(name = "rpo256")]
+    #[serde(rename = "rpo256")]
+    Rpo256,
+}
+
+// PROOF PARAMETERS
+// ================================================================================================
+
+/// Fully resolved set of STARK proof parameters which is used to build [ProvingOptions] and which
+/// is reported alongside the benchmark results.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofParams {
+    pub num_queries: usize,
+    pub blowup_factor: usize,
+    pub grinding_factor: u32,
+    pub field_extension: FieldExtensionArg,
+    pub fri_folding_factor: usize,
+    pub fri_remainder_max_degree: usize,
+    pub hash_fn: HashFunctionArg,
+}
+
+impl ProofParams {
+    /// Returns the parameters of [ProvingOptions::with_96_bit_security].
+    pub fn with_96_bit_security(recursive: bool) -> Self {
+        let (fri_folding_factor, fri_remainder_max_degree, hash_fn) = if recursive {
+            (4, 7, HashFunctionArg::Rpo256)
+        } else {
+            (8, 255, HashFunctionArg::Blake3_192)
+        };
+        Self {
+            num_queries: 27,
+            blowup_factor: 8,
+            grinding_factor: 16,
+            field_extension: FieldExtensionArg::Quadratic,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            hash_fn,
+        }
+    }
+
+    /// Returns the parameters of [ProvingOptions::with_128_bit_security].
+    pub fn with_128_bit_security(recursive: bool) -> Self {
+        let (fri_folding_factor, fri_remainder_max_degree, hash_fn) = if recursive {
+            (4, 7, HashFunctionArg::Rpo256)
+        } else {
+            (8, 255, HashFunctionArg::Blake3_256)
+        };
+        Self {
+            num_queries: 27,
+            blowup_factor: 16,
+            grinding_factor: 21,
+            field_extension: FieldExtensionArg::Cubic,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            hash_fn,
+        }
+    }
+
+    /// Checks the parameters against the limits of the STARK prover.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_queries == 0 || self.num_queries > MAX_NUM_QUERIES {
+            return Err(format!(
+                "number of queries must be between 1 and {MAX_NUM_QUERIES}, got {}",
+                self.num_queries
+            ));
+        }
+        if !self.blowup_factor.is_power_of_two()
+            || !(MIN_BLOWUP_FACTOR..=MAX_BLOWUP_FACTOR).contains(&self.blowup_factor)
+        {
+            return Err(format!(
+                "blowup factor must be a power of two between {MIN_BLOWUP_FACTOR} and \
+                 {MAX_BLOWUP_FACTOR}, got {}",
+                self.blowup_factor
+            ));
+        }
+        if self.grinding_factor > MAX_GRINDING_FACTOR {
+            return Err(format!(
+                "grinding factor cannot be greater than {MAX_GRINDING_FACTOR}, got {}",
+                self.grinding_factor
+            ));
+        }
+        if !self.fri_folding_factor.is_power_of_two()
+            || !(MIN_FRI_FOLDING_FACTOR..=MAX_FRI_FOLDING_FACTOR).contains(&self.fri_folding_factor)
+        {
+            return Err(format!(
+                "FRI folding factor must be a power of two between {MIN_FRI_FOLDING_FACTOR} and \
+                 {MAX_FRI_FOLDING_FACTOR}, got {}",
+                self.fri_folding_factor
+            ));
+        }
+        if !self
+            .fri_remainder_max_degree
+            .checked_add(1)
+            .is_some_and(usize::is_power_of_two)
+            || self.fri_remainder_max_degree > MAX_FRI_REMAINDER_DEGREE
+        {
+            return Err(format!(
+                "FRI remainder max degree must be one less than a power of two and at most \
+                 {MAX_FRI_REMAINDER_DEGREE}, got {}",
+                self.fri_remainder_max_degree
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the proving options described by these parameters.
+    pub fn to_proving_options(&self) -> ProvingOptions {
+        let field_extension = match self.field_extension {
+            FieldExtensionArg::None => FieldExtension::None,
+            FieldExtensionArg::Quadratic => FieldExtension::Quadratic,
+            FieldExtensionArg::Cubic => FieldExtension::Cubic,
+        };
+        let hash_fn = match self.hash_fn {
+            HashFunctionArg::Blake3_192 => HashFunction::Blake3_192,
+            HashFunctionArg::Blake3_256 => HashFunction::Blake3_256,
+            HashFunctionArg::Rpo256 => HashFunction::Rpo256,
+        };
+        ProvingOptions::new(
+            self.num_queries,
+            self.blowup_factor,
+            self.grinding_factor,
+            field_extension,
+            self.fri_folding_factor,
+            self.fri_remainder_max_degree,
+            hash_fn,
+        )
+    }
+}
+
+impl Default for ProofParams {
+    fn default() -> Self {
+        Self::with_96_bit_security(false)
+    }
+}
+
+impl fmt::Display for ProofParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "queries={} blowup={} grinding={} extension={} fri_folding={} fri_remainder={} hash={}",
+            self.num_queries,
+            self.blowup_factor,
+            self.grinding_factor,
+            self.field_extension.to_possible_value().unwrap().get_name(),
+            self.fri_folding_factor,
+            self.fri_remainder_max_degree,
+            self.hash_fn.to_possible_value().unwrap().get_name(),
+        )
+    }
+}
+
+#[test]
+fn test_proof_params() {
+    assert_eq!(
+        ProofParams::with_96_bit_security(false).to_proving_options(),
+        ProvingOptions::with_96_bit_security(false)
+    );
+    assert_eq!(
+        ProofParams::with_128_bit_security(true).to_proving_options(),
+        ProvingOptions::with_128_bit_security(true)
+    );
+
+    let mut params = ProofParams::with_96_bit_security(false);
+    params.blowup_factor = 6;
+    assert!(params.validate().is_err());
+    params.blowup_factor = 4;
+    assert!(params.validate().is_err());
+    params.blowup_factor = 8;
+    params.fri_remainder_max_degree = usize::MAX;
+    assert!(params.validate().is_err());
+    params.fri_remainder_max_degree = 100;
+    assert!(params.validate().is_err());
+    params.fri_remainder_max_degree = 127;
+    assert!(params.validate().is_ok());
+}
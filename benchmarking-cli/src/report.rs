@@ -0,0 +1,422 @@
+use crate::benchmark::BenchmarkResult;
+use crate::error::CliError;
+use crate::memory::format_mib;
+use crate::proof_breakdown::ProofBreakdown;
+use crate::proving_options::ProofParams;
+use crate::stats::{PhaseStats, HIGH_VARIANCE_THRESHOLD};
+use crate::trace_breakdown::TraceBreakdown;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Outcome of benchmarking a single example as part of a suite run.
+pub struct ExampleReport {
+    pub name: String,
+    pub outcome: Result<BenchmarkResult, CliError>,
+}
+
+// OUTPUT FORMAT
+// ================================================================================================
+
+/// Format in which the benchmark results are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text, a table when more than one example is benchmarked.
+    Text,
+    /// A JSON array with one record per example.
+    Json,
+    /// A CSV table with a header and one row per example.
+    Csv,
+}
+
+/// Renders the reports in the requested format.
+pub fn render(reports: &[ExampleReport], format: OutputFormat) -> Result<String, String> {
+    match format {
+        OutputFormat::Text => match reports {
+            [ExampleReport {
+                outcome: Ok(result),
+                ..
+            }] => Ok(format_result(result)),
+            _ => Ok(format_summary_table(reports)),
+        },
+        OutputFormat::Json => {
+            let records: Vec<BenchmarkRecord> = reports.iter().map(BenchmarkRecord::from).collect();
+            serde_json::to_string_pretty(&records)
+                .map(|json| json + "\n")
+                .map_err(|err| format!("Failed to serialize benchmark results - {err}"))
+        }
+        OutputFormat::Csv => Ok(format_csv(reports)),
+    }
+}
+
+// TEXT
+// ================================================================================================
+
+/// Formats one line per phase for a single benchmarked example.
+fn format_result(result: &BenchmarkResult) -> String {
+    let mut out = String::new();
+    writeln!(out, "Compilation Time (cold): {}", result.compilation_time).unwrap();
+    writeln!(
+        out,
+        "Compilation Time (hot): {}",
+        result.hot_compilation_time
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "Execution Time: {} steps in {}",
+        result.trace_len, result.execution_time
+    )
+    .unwrap();
+    writeln!(out, "Trace Breakdown: {}", result.trace_breakdown).unwrap();
+    writeln!(out, "Proving Time: {}", result.proving_time).unwrap();
+    writeln!(
+        out,
+        "Proof Size: {} bytes ({}-bit conjectured security)",
+        result.proof_size, result.security_level
+    )
+    .unwrap();
+    writeln!(out, "Proof Breakdown: {}", result.proof_breakdown).unwrap();
+    writeln!(out, "Proof Options: {}", result.proof_params).unwrap();
+    writeln!(out, "Verification Time: {}", result.verification_time).unwrap();
+    let peak_heap =
+        phases(result).map(|(name, stats)| format!("{name} {}", format_mib(stats.peak_heap())));
+    writeln!(out, "Peak Heap: {}", peak_heap.join(" / ")).unwrap();
+    writeln!(out, "Result: {:?}", result.stack_output).unwrap();
+    out
+}
+
+/// Formats a consolidated table with one row per benchmarked example. Timings are the medians over
+/// all measured iterations and are marked with `*` if their variance is high, and the peak heap is
+/// the highest of all phases. Failed examples are listed with their error message in place of the
+/// metrics.
+fn format_summary_table(reports: &[ExampleReport]) -> String {
+    let name_width = reports
+        .iter()
+        .map(|report| report.name.len())
+        .chain(["Example".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    let header = format!(
+        "{:<name_width$} | {:>12} | {:>12} | {:>12} | {:>10} | {:>20} | {:>12} | {:>12} | {:>10} | {:>10} | Result",
+        "Example",
+        "Cold (ms)",
+        "Hot (ms)",
+        "Execute (ms)",
+        "Trace len",
+        "Dominant (used)",
+        "Prove (ms)",
+        "Verify (ms)",
+        "Proof (B)",
+        "Peak heap"
+    );
+    writeln!(out, "{header}").unwrap();
+    writeln!(out, "{}", "-".repeat(header.len())).unwrap();
+
+    for report in reports {
+        match &report.outcome {
+            Ok(result) => writeln!(
+                out,
+                "{:<name_width$} | {:>12} | {:>12} | {:>12} | {:>10} | {:>20} | {:>12} | {:>12} | {:>10} | {:>10} | {:?}",
+                report.name,
+                median(&result.compilation_time),
+                median(&result.hot_compilation_time),
+                median(&result.execution_time),
+                result.trace_len,
+                format!(
+                    "{} ({:.0}%)",
+                    result.trace_breakdown.dominant_segment(),
+                    result.trace_breakdown.utilization()
+                ),
+                median(&result.proving_time),
+                median(&result.verification_time),
+                result.proof_size,
+                format_mib(peak_heap(result)),
+                result.stack_output,
+            ),
+            Err(err) => writeln!(
+                out,
+                "{:<name_width$} | FAILED: {}",
+                report.name,
+                err.to_string().replace('\n', " ")
+            ),
+        }
+        .unwrap();
+    }
+
+    let noisy = reports.iter().any(|report| {
+        report.outcome.as_ref().is_ok_and(|result| {
+            phases(result)
+                .iter()
+                .any(|(_, stats)| stats.is_high_variance())
+        })
+    });
+    if noisy {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "* high variance (stddev above {:.0}% of the mean), consider more iterations",
+            HIGH_VARIANCE_THRESHOLD * 100.0
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+/// Formats the median of `stats` in milliseconds, marking results with high variance.
+fn median(stats: &PhaseStats) -> String {
+    let marker = if stats.is_high_variance() { "*" } else { "" };
+    format!("{marker}{:.2}", stats.median())
+}
+
+/// Returns the highest peak heap usage of all phases of a benchmark result.
+fn peak_heap(result: &BenchmarkResult) -> usize {
+    phases(result)
+        .iter()
+        .map(|(_, stats)| stats.peak_heap())
+        .max()
+        .unwrap_or_default()
+}
+
+/// Returns the measured phases of a benchmark result together with their names.
+fn phases(result: &BenchmarkResult) -> [(&'static str, &PhaseStats); 5] {
+    [
+        ("compilation_cold", &result.compilation_time),
+        ("compilation_hot", &result.hot_compilation_time),
+        ("execution", &result.execution_time),
+        ("proving", &result.proving_time),
+        ("verification", &result.verification_time),
+    ]
+}
+
+// CSV
+// ================================================================================================
+
+/// Formats the reports as CSV with a header row. The trace breakdown contributes the row count of
+/// each segment and the proof breakdown the size of each proof component in bytes. Each phase
+/// contributes its min/median/mean/stddev/max columns in milliseconds and its peak heap in bytes,
+/// and the stack output is a space separated list.
+fn format_csv(reports: &[ExampleReport]) -> String {
+    const STATS: [&str; 5] = ["min", "median", "mean", "stddev", "max"];
+    let phase_names = phases(&BenchmarkResult::default()).map(|(name, _)| name);
+
+    let mut columns = [
+        "example",
+        "security_level",
+        "trace_len",
+        "main_rows",
+        "range_checker_rows",
+        "hasher_rows",
+        "bitwise_rows",
+        "memory_rows",
+        "kernel_rom_rows",
+        "chiplets_rows",
+        "padded_len",
+        "headroom_rows",
+        "proof_size",
+        "proof_context_bytes",
+        "proof_commitments_bytes",
+        "proof_trace_queries_bytes",
+        "proof_constraint_queries_bytes",
+        "proof_ood_frame_bytes",
+        "proof_fri_bytes",
+        "proof_pow_nonce_bytes",
+        "proof_params",
+    ]
+    .map(String::from)
+    .to_vec();
+    for phase in phase_names {
+        columns.extend(STATS.iter().map(|stat| format!("{phase}_{stat}_ms")));
+        columns.push(format!("{phase}_peak_heap_bytes"));
+    }
+    columns.extend(["iterations", "stack_output", "error"].map(String::from));
+
+    let mut out = columns.join(",") + "\n";
+    for report in reports {
+        let mut row = vec![csv_field(&report.name)];
+        match &report.outcome {
+            Ok(result) => {
+                row.push(result.security_level.to_string());
+                row.push(result.trace_len.to_string());
+                let breakdown = &result.trace_breakdown;
+                let rows = [
+                    breakdown.main_rows,
+                    breakdown.range_checker_rows,
+                    breakdown.hasher_rows,
+                    breakdown.bitwise_rows,
+                    breakdown.memory_rows,
+                    breakdown.kernel_rom_rows,
+                    breakdown.chiplets_rows,
+                    breakdown.padded_len,
+                    breakdown.headroom(),
+                ];
+                row.extend(rows.iter().map(usize::to_string));
+                row.push(result.proof_size.to_string());
+                let proof = &result.proof_breakdown;
+                let sizes = [
+                    proof.context,
+                    proof.commitments,
+                    proof.trace_queries,
+                    proof.constraint_queries,
+                    proof.ood_frame,
+                    proof.fri_proof,
+                    proof.pow_nonce,
+                ];
+                row.extend(sizes.iter().map(usize::to_string));
+                row.push(result.proof_params.to_string());
+                for (_, stats) in phases(result) {
+                    let values = [
+                        stats.min(),
+                        stats.median(),
+                        stats.mean(),
+                        stats.stddev(),
+                        stats.max(),
+                    ];
+                    row.extend(values.iter().map(|value| format!("{value:.3}")));
+                    row.push(stats.peak_heap().to_string());
+                }
+                row.push(result.proving_time.num_samples().to_string());
+                let stack = result
+                    .stack_output
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>();
+                row.push(stack.join(" "));
+                row.push(String::new());
+            }
+            Err(err) => {
+                row.resize(columns.len() - 1, String::new());
+                row.push(csv_field(&err.to_string()));
+            }
+        }
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// JSON
+// ================================================================================================
+
+/// Machine readable record of the benchmark of a single example. Metrics are `null` if the
+/// example failed, in which case `error` holds the reason.
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    pub example: String,
+    pub security_level: Option<u32>,
+    pub trace_len: Option<usize>,
+    #[serde(default)]
+    pub trace_breakdown: Option<TraceBreakdown>,
+    pub proof_size: Option<usize>,
+    #[serde(default)]
+    pub proof_breakdown: Option<ProofBreakdown>,
+    #[serde(default)]
+    pub proof_params: Option<ProofParams>,
+    pub compilation_cold: Option<PhaseSummary>,
+    pub compilation_hot: Option<PhaseSummary>,
+    pub execution: Option<PhaseSummary>,
+    pub proving: Option<PhaseSummary>,
+    pub verification: Option<PhaseSummary>,
+    pub stack_output: Option<Vec<u64>>,
+    pub error: Option<String>,
+}
+
+/// Summary statistics of a single phase, all timings in milliseconds.
+#[derive(Serialize, Deserialize)]
+pub struct PhaseSummary {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    pub stddev_ms: f64,
+    pub max_ms: f64,
+    pub iterations: usize,
+    pub high_variance: bool,
+    #[serde(default)]
+    pub peak_heap_bytes: usize,
+}
+
+impl From<&PhaseStats> for PhaseSummary {
+    fn from(stats: &PhaseStats) -> Self {
+        Self {
+            min_ms: stats.min(),
+            median_ms: stats.median(),
+            mean_ms: stats.mean(),
+            stddev_ms: stats.stddev(),
+            max_ms: stats.max(),
+            iterations: stats.num_samples(),
+            high_variance: stats.is_high_variance(),
+            peak_heap_bytes: stats.peak_heap(),
+        }
+    }
+}
+
+impl From<&ExampleReport> for BenchmarkRecord {
+    fn from(report: &ExampleReport) -> Self {
+        let result = report.outcome.as_ref().ok();
+        Self {
+            example: report.name.clone(),
+            security_level: result.map(|result| result.security_level),
+            trace_len: result.map(|result| result.trace_len),
+            trace_breakdown: result.map(|result| result.trace_breakdown.clone()),
+            proof_size: result.map(|result| result.proof_size),
+            proof_breakdown: result.map(|result| result.proof_breakdown.clone()),
+            proof_params: result.map(|result| result.proof_params.clone()),
+            compilation_cold: result.map(|result| (&result.compilation_time).into()),
+            compilation_hot: result.map(|result| (&result.hot_compilation_time).into()),
+            execution: result.map(|result| (&result.execution_time).into()),
+            proving: result.map(|result| (&result.proving_time).into()),
+            verification: result.map(|result| (&result.verification_time).into()),
+            stack_output: result.map(|result| result.stack_output.clone()),
+            error: report.outcome.as_ref().err().map(CliError::to_string),
+        }
+    }
+}
+
+#[test]
+fn test_format_csv() {
+    use std::time::Duration;
+
+    let mut result = BenchmarkResult {
+        trace_len: 1024,
+        proof_size: 30000,
+        security_level: 96,
+        stack_output: vec![8, 0],
+        ..Default::default()
+    };
+    result.proving_time.push(Duration::from_millis(10));
+
+    let reports = [
+        ExampleReport {
+            name: "conditional".to_string(),
+            outcome: Ok(result),
+        },
+        ExampleReport {
+            name: "collatz".to_string(),
+            outcome: Err(CliError::Assembly(
+                "Failed to compile program - \"u32mod\", invalid".to_string(),
+            )),
+        },
+    ];
+    let csv = format_csv(&reports);
+    let lines: Vec<&str> = csv.lines().collect();
+
+    let num_columns = lines[0].split(',').count();
+    assert_eq!(num_columns, 5 + 9 + 7 + 5 * 6 + 3);
+    assert!(lines[1]
+        .starts_with("conditional,96,1024,0,0,0,0,0,0,0,0,0,30000,0,0,0,0,0,0,0,queries=27 "));
+    assert!(lines[1].ends_with(",1,8 0,"));
+    assert!(lines[2].starts_with(&format!("collatz{}", ",".repeat(num_columns - 1))));
+    assert!(lines[2].ends_with("\"Failed to compile program - \"\"u32mod\"\", invalid\""));
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Relative standard deviation (stddev / mean) above which a measurement is considered too noisy
+/// to be compared reliably.
+pub const HIGH_VARIANCE_THRESHOLD: f64 = 0.1;
+
+// PHASE STATS
+// ================================================================================================
+
+/// Timings and peak heap usage collected for a single phase (e.g. proving) over all measured
+/// iterations.
+#[derive(Clone, Debug, Default)]
+pub struct PhaseStats {
+    samples: Vec<Duration>,
+    peak_heap: usize,
+}
+
+impl PhaseStats {
+    /// Records the duration of one measured iteration.
+    pub fn push(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    /// Records the peak heap usage of one measured iteration, keeping the highest one.
+    pub fn record_peak_heap(&mut self, bytes: usize) {
+        self.peak_heap = self.peak_heap.max(bytes);
+    }
+
+    /// Returns the highest number of bytes allocated on the heap during any measured iteration.
+    pub fn peak_heap(&self) -> usize {
+        self.peak_heap
+    }
+
+    /// Returns the number of measured iterations.
+    pub fn num_samples(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns the fastest measured iteration in milliseconds.
+    pub fn min(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.millis().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the slowest measured iteration in milliseconds.
+    pub fn max(&self) -> f64 {
+        self.millis().fold(0.0, f64::max)
+    }
+
+    /// Returns the mean of all measured iterations in milliseconds.
+    pub fn mean(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.millis().sum::<f64>() / self.num_samples() as f64
+    }
+
+    /// Returns the median of all measured iterations in milliseconds.
+    pub fn median(&self) -> f64 {
+        let mut millis: Vec<f64> = self.millis().collect();
+        millis.sort_by(f64::total_cmp);
+        match millis.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (millis[len / 2 - 1] + millis[len / 2]) / 2.0,
+            len => millis[len / 2],
+        }
+    }
+
+    /// Returns the sample standard deviation of all measured iterations in milliseconds.
+    pub fn stddev(&self) -> f64 {
+        if self.num_samples() < 2 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        let variance = self.millis().map(|x| (x - mean).powi(2)).sum::<f64>()
+            / (self.num_samples() - 1) as f64;
+        variance.sqrt()
+    }
+
+    /// Returns true if the relative standard deviation of the samples exceeds
+    /// [HIGH_VARIANCE_THRESHOLD], i.e. differences smaller than the noise should not be trusted.
+    pub fn is_high_variance(&self) -> bool {
+        let mean = self.mean();
+        mean > 0.0 && self.stddev() / mean > HIGH_VARIANCE_THRESHOLD
+    }
+
+    fn millis(&self) -> impl Iterator<Item = f64> + '_ {
+        self.samples
+            .iter()
+            .map(|sample| sample.as_secs_f64() * 1000.0)
+    }
+}
+
+impl fmt::Display for PhaseStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.num_samples() <= 1 {
+            return write!(f, "{:.0} ms", self.mean());
+        }
+        write!(
+            f,
+            "min {:.2} / median {:.2} / mean {:.2} / stddev {:.2} / max {:.2} ms over {} iterations",
+            self.min(),
+            self.median(),
+            self.mean(),
+            self.stddev(),
+            self.max(),
+            self.num_samples()
+        )?;
+        if self.is_high_variance() {
+            write!(f, " [HIGH VARIANCE]")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_phase_stats() {
+    let mut stats = PhaseStats::default();
+    for millis in [4, 2, 8, 6] {
+        stats.push(Duration::from_millis(millis));
+    }
+
+    assert_eq!(stats.min(), 2.0);
+    assert_eq!(stats.max(), 8.0);
+    assert_eq!(stats.mean(), 5.0);
+    assert_eq!(stats.median(), 5.0);
+    assert!((stats.stddev() - 2.581_988_897).abs() < 1e-6);
+    assert!(stats.is_high_variance());
+
+    let mut stable = PhaseStats::default();
+    for _ in 0..3 {
+        stable.push(Duration::from_millis(10));
+    }
+    assert_eq!(stable.stddev(), 0.0);
+    assert!(!stable.is_high_variance());
+}
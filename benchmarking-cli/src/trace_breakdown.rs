@@ -0,0 +1,124 @@
+use miden_vm::ExecutionTrace;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// TRACE BREAKDOWN
+// ================================================================================================
+
+/// Number of rows contributed by each segment of the execution trace. The tallest segment
+/// determines the length of the trace, which is padded to the next power of two (including the
+/// random rows appended by the prover) and hence determines the proving cost.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceBreakdown {
+    pub main_rows: usize,
+    pub range_checker_rows: usize,
+    pub hasher_rows: usize,
+    pub bitwise_rows: usize,
+    pub memory_rows: usize,
+    pub kernel_rom_rows: usize,
+    /// Rows of all chiplets together, including one mandatory padding row.
+    pub chiplets_rows: usize,
+    /// Length of the tallest segment.
+    pub trace_len: usize,
+    /// Length of the trace after padding it to a power of two.
+    pub padded_len: usize,
+}
+
+impl TraceBreakdown {
+    /// Collects the segment lengths of the provided execution trace.
+    pub fn new(trace: &ExecutionTrace) -> Self {
+        let summary = trace.trace_len_summary();
+        let chiplets = summary.chiplets_trace_len();
+        Self {
+            main_rows: summary.main_trace_len(),
+            range_checker_rows: summary.range_trace_len(),
+            hasher_rows: chiplets.hash_chiplet_len(),
+            bitwise_rows: chiplets.bitwise_chiplet_len(),
+            memory_rows: chiplets.memory_chiplet_len(),
+            kernel_rom_rows: chiplets.kernel_rom_len(),
+            chiplets_rows: chiplets.trace_len(),
+            trace_len: summary.trace_len(),
+            padded_len: summary.padded_trace_len(),
+        }
+    }
+
+    /// Returns the name of the segment which determines the trace length.
+    pub fn dominant_segment(&self) -> &'static str {
+        if self.chiplets_rows >= self.main_rows && self.chiplets_rows >= self.range_checker_rows {
+            "chiplets"
+        } else if self.range_checker_rows >= self.main_rows {
+            "range checker"
+        } else {
+            "main"
+        }
+    }
+
+    /// Returns the number of rows the tallest segment can still grow by before the padded trace
+    /// length doubles.
+    pub fn headroom(&self) -> usize {
+        self.padded_len
+            .saturating_sub(self.trace_len + ExecutionTrace::NUM_RAND_ROWS)
+    }
+
+    /// Returns the share of the padded trace which is used by the tallest segment, in percent.
+    pub fn utilization(&self) -> f64 {
+        if self.padded_len == 0 {
+            return 0.0;
+        }
+        (self.trace_len + ExecutionTrace::NUM_RAND_ROWS) as f64 / self.padded_len as f64 * 100.0
+    }
+}
+
+impl fmt::Display for TraceBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "main {} / range checker {} / chiplets {} (hasher {}, bitwise {}, memory {}, \
+             kernel ROM {}) rows, padded to {} ({} dominates, {:.1}% used, {} rows to the next \
+             power of two)",
+            self.main_rows,
+            self.range_checker_rows,
+            self.chiplets_rows,
+            self.hasher_rows,
+            self.bitwise_rows,
+            self.memory_rows,
+            self.kernel_rom_rows,
+            self.padded_len,
+            self.dominant_segment(),
+            self.utilization(),
+            self.headroom(),
+        )
+    }
+}
+
+#[test]
+fn test_trace_breakdown() {
+    let breakdown = TraceBreakdown {
+        main_rows: 700,
+        range_checker_rows: 300,
+        hasher_rows: 800,
+        bitwise_rows: 16,
+        memory_rows: 4,
+        kernel_rom_rows: 0,
+        chiplets_rows: 821,
+        trace_len: 821,
+        padded_len: 1024,
+    };
+    assert_eq!(breakdown.dominant_segment(), "chiplets");
+    assert_eq!(
+        breakdown.headroom(),
+        1024 - 821 - ExecutionTrace::NUM_RAND_ROWS
+    );
+
+    let breakdown = TraceBreakdown {
+        main_rows: 1023,
+        range_checker_rows: 40,
+        chiplets_rows: 1,
+        trace_len: 1023,
+        padded_len: 1024,
+        ..Default::default()
+    };
+    assert_eq!(breakdown.dominant_segment(), "main");
+    assert_eq!(breakdown.headroom(), 0);
+    assert_eq!(breakdown.utilization(), 100.0);
+}
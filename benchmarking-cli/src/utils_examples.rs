@@ -0,0 +1,181 @@
+use miden_examples_core::Outputs;
+use std::fs;
+use std::path::Path;
+
+/// Directory which contains the bundled Miden examples. It is resolved relative to the CLI crate
+/// so that the CLI can be launched from any directory.
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../examples");
+
+/// Returns the names of all examples in `dir`, i.e. of all `.masm` files, sorted alphabetically.
+/// If `filter` is provided, only examples whose name matches the glob pattern are returned.
+pub fn discover_examples(dir: &Path, filter: Option<&str>) -> Result<Vec<String>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| {
+        format!(
+            "Failed to read examples directory {} - {}",
+            dir.display(),
+            err
+        )
+    })?;
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("masm") {
+            continue;
+        }
+        let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        if filter.is_some_and(|pattern| !matches_pattern(pattern, &name)) {
+            continue;
+        }
+        examples.push(name);
+    }
+
+    examples.sort();
+    Ok(examples)
+}
+
+/// Reads the program and the inputs at the provided paths. A missing inputs file is treated as
+/// empty inputs.
+pub fn read_program_files(program: &Path, inputs: &Path) -> Result<(String, String), String> {
+    let program_string = fs::read_to_string(program)
+        .map_err(|err| format!("Failed to read program {} - {err}", program.display()))?;
+
+    let input_string = if inputs.exists() {
+        fs::read_to_string(inputs)
+            .map_err(|err| format!("Failed to read inputs {} - {err}", inputs.display()))?
+    } else {
+        String::new()
+    };
+
+    Ok((program_string, input_string))
+}
+
+/// Reads the expected stack output of a program from its `.outputs` file, which holds a JSON
+/// object with a `stack_output` array. Returns `None` if there is no such file.
+pub fn read_expected_output(path: &Path) -> Result<Option<Vec<u64>>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let outputs = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read expected outputs {} - {err}", path.display()))?;
+    let outputs: Outputs = serde_json::from_str(&outputs).map_err(|err| {
+        format!(
+            "Failed to parse expected outputs {} - {err}",
+            path.display()
+        )
+    })?;
+    Ok(Some(outputs.stack_output))
+}
+
+/// Checks the stack output of a program against its expected stack output. Only the top
+/// `expected.len()` elements of the stack are compared.
+pub fn check_stack_output(expected: &[u64], actual: &[u64]) -> Result<(), String> {
+    if actual.starts_with(expected) {
+        return Ok(());
+    }
+    let actual = &actual[..expected.len().min(actual.len())];
+    Err(format!(
+        "Unexpected stack output - expected {expected:?}, got {actual:?}"
+    ))
+}
+
+/// Matches `name` against a glob `pattern` where `*` matches any sequence of characters and `?`
+/// matches exactly one character.
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // position of the last `*` in the pattern and the name position it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, n));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[test]
+fn test_matches_pattern() {
+    assert!(matches_pattern("merkle*", "merkle_inclusion"));
+    assert!(matches_pattern("*", "fibonacci"));
+    assert!(matches_pattern("*_of_*", "game_of_life_4x4"));
+    assert!(matches_pattern("c?talan", "catalan"));
+    assert!(matches_pattern("collatz", "collatz"));
+    assert!(!matches_pattern("merkle*", "fibonacci"));
+    assert!(!matches_pattern("fib", "fibonacci"));
+    assert!(!matches_pattern("*prime?", "nprime"));
+}
+
+#[test]
+fn test_check_stack_output() {
+    assert!(check_stack_output(&[8], &[8, 0, 0]).is_ok());
+    assert!(check_stack_output(&[], &[8, 0, 0]).is_ok());
+    assert_eq!(
+        check_stack_output(&[8, 1], &[8, 0, 0]),
+        Err("Unexpected stack output - expected [8, 1], got [8, 0]".to_string())
+    );
+}
+
+/// Executes every bundled example and compares its stack output with the expected one. An example
+/// without a `.outputs` file is a failure, so that no example drops out of the regression suite.
+#[test]
+fn test_examples() {
+    use miden_air::ExecutionOptions;
+    use miden_examples_core::{Inputs, MidenProgram};
+
+    let dir = Path::new(EXAMPLES_DIR);
+    let mut failures = Vec::new();
+    let mut num_checked = 0;
+    for name in discover_examples(dir, None).unwrap() {
+        let Some(expected) = read_expected_output(&dir.join(format!("{name}.outputs"))).unwrap()
+        else {
+            failures.push(format!("{name}: no .outputs file"));
+            continue;
+        };
+        let (program_string, input_string) = read_program_files(
+            &dir.join(format!("{name}.masm")),
+            &dir.join(format!("{name}.inputs")),
+        )
+        .unwrap();
+
+        let result = (|| {
+            let mut inputs = Inputs::new();
+            inputs.deserialize_inputs(&input_string)?;
+            let mut program = MidenProgram::new(program_string, false);
+            program.compile_program()?;
+            let mut host = MidenProgram::new_host(inputs.advice_provider)?;
+            let trace = miden_vm::execute(
+                &program.program.unwrap(),
+                inputs.stack_inputs,
+                &mut host,
+                ExecutionOptions::default(),
+            )
+            .map_err(|err| err.to_string())?;
+            check_stack_output(&expected, &trace.stack_outputs().as_int_vec())
+        })();
+        if let Err(err) = result {
+            failures.push(format!("{name}: {err}"));
+        }
+        num_checked += 1;
+    }
+
+    assert!(num_checked > 0, "no example has a .outputs file");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
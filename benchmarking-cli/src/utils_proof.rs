@@ -0,0 +1,263 @@
+use miden_air::{ProcessorAir, PublicInputs};
+use miden_examples_core::Outputs;
+use miden_vm::crypto::{
+    Blake3_192, Blake3_256, Rpo256, RpoRandomCoin, Rpx256, RpxRandomCoin, WinterRandomCoin,
+};
+use miden_vm::{Digest, ExecutionProof, HashFunction, ProgramInfo, StackInputs, StackOutputs};
+use std::fs;
+use std::path::{Path, PathBuf};
+use winter_verifier::{crypto::MerkleTree, AcceptableOptions};
+
+/// Conjectured security level in bits below which proofs are rejected by default, the one of
+/// the 96-bit preset.
+pub const MIN_SECURITY_LEVEL: u32 = 96;
+
+// SAVED PROOF
+// ================================================================================================
+
+/// An execution proof together with the outputs of the proven program, as written to disk by the
+/// `prove` command and by `bench --save-proof`.
+pub struct SavedProof {
+    pub proof: ExecutionProof,
+    pub outputs: Outputs,
+}
+
+impl SavedProof {
+    /// Bundles a proof with the outputs the verifier needs to check it. `cycles` is the number of
+    /// cycles the program ran for, i.e. the length of its main trace before padding.
+    pub fn new(
+        proof: ExecutionProof,
+        program_hash: &Digest,
+        stack_outputs: &StackOutputs,
+        cycles: usize,
+    ) -> Self {
+        let outputs = Outputs {
+            program_hash: program_hash.to_string(),
+            // the full stack is needed to verify the proof
+            stack_output: stack_outputs.as_int_vec(),
+            overflow_addrs: Vec::new(),
+            cycles: Some(cycles),
+            trace_len: Some(proof.stark_proof().trace_info().length()),
+            proof: None,
+            merkle_roots: Default::default(),
+        };
+        Self { proof, outputs }
+    }
+
+    /// Writes the proof bytes to `proof_path` and the outputs as JSON to `outputs_path`.
+    pub fn save(&self, proof_path: &Path, outputs_path: &Path) -> Result<(), String> {
+        fs::write(proof_path, self.proof.to_bytes())
+            .map_err(|err| format!("Failed to write proof to {} - {err}", proof_path.display()))?;
+
+        let outputs = serde_json::to_string_pretty(&self.outputs)
+            .map_err(|err| format!("Failed to serialize outputs - {err}"))?;
+        fs::write(outputs_path, outputs + "\n").map_err(|err| {
+            format!(
+                "Failed to write outputs to {} - {err}",
+                outputs_path.display()
+            )
+        })
+    }
+
+    /// Reads a proof and its outputs. If no proof file is provided, the proof is taken from the
+    /// `proof` field of the outputs, which is where the playground's `prove_program` returns it.
+    /// If no outputs file is provided, the companion file of the proof is read, see
+    /// [outputs_path].
+    pub fn load(proof_path: Option<&Path>, outputs_path: Option<&Path>) -> Result<Self, String> {
+        let outputs_path = match (outputs_path, proof_path) {
+            (Some(outputs_path), _) => outputs_path.to_path_buf(),
+            (None, Some(proof_path)) => self::outputs_path(proof_path),
+            (None, None) => return Err("Either a proof or an outputs file is required".into()),
+        };
+        let outputs = fs::read_to_string(&outputs_path).map_err(|err| {
+            format!(
+                "Failed to read outputs file {} - {err}",
+                outputs_path.display()
+            )
+        })?;
+        let mut outputs: Outputs = serde_json::from_str(&outputs).map_err(|err| {
+            format!(
+                "Failed to deserialize outputs {} - {err}",
+                outputs_path.display()
+            )
+        })?;
+
+        let proof = match (proof_path, outputs.proof.take()) {
+            (Some(proof_path), _) => fs::read(proof_path)
+                .map_err(|err| format!("Failed to read proof {} - {err}", proof_path.display()))?,
+            (None, Some(proof)) => proof,
+            (None, None) => {
+                return Err(format!(
+                    "No proof file provided and {} does not contain a proof",
+                    outputs_path.display()
+                ))
+            }
+        };
+        let proof = ExecutionProof::from_bytes(&proof)
+            .map_err(|err| format!("Failed to deserialize proof - {err}"))?;
+
+        Ok(Self { proof, outputs })
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies a proof built with any proof parameters and returns its conjectured security level in
+/// bits. Unlike `miden_vm::verify`, which only accepts the presets of `ProvingOptions`, the proof
+/// is verified with the parameters it was built with, and it is rejected if its conjectured
+/// security is below `min_security`.
+pub fn verify_proof(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    min_security: u32,
+) -> Result<u32, String> {
+    let security_level = proof.security_level();
+    if security_level < min_security {
+        return Err(format!(
+            "the proof has {security_level}-bit conjectured security, less than the required \
+             {min_security} bits"
+        ));
+    }
+
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let options = AcceptableOptions::OptionSet(vec![proof.stark_proof().options().clone()]);
+    let (hash_fn, proof) = proof.into_parts();
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            winter_verifier::verify::<ProcessorAir, Blake3_192, WinterRandomCoin<_>, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+        HashFunction::Blake3_256 => {
+            winter_verifier::verify::<ProcessorAir, Blake3_256, WinterRandomCoin<_>, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+        HashFunction::Rpo256 => {
+            winter_verifier::verify::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+        HashFunction::Rpx256 => {
+            winter_verifier::verify::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+    }
+    .map_err(|err| err.to_string())?;
+    Ok(security_level)
+}
+
+/// Returns the path of the outputs file which accompanies the proof at `proof_path`, e.g.
+/// `fibonacci.outputs.json` for `fibonacci.proof`.
+pub fn outputs_path(proof_path: &Path) -> PathBuf {
+    proof_path.with_extension("outputs.json")
+}
+
+#[test]
+fn test_save_and_load_proof() {
+    use miden_examples_core::MidenProgram;
+    use miden_vm::{DefaultHost, ProvingOptions, StackInputs};
+
+    let mut program = MidenProgram::new("begin push.1 push.2 add swap drop end", false);
+    program.compile_program().unwrap();
+    let program_info = program.program_info.clone().unwrap();
+    let (output, proof) = miden_vm::prove(
+        &program.program.unwrap(),
+        StackInputs::default(),
+        &mut DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("miden-saved-proof-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let proof_path = dir.join("add.proof");
+    let saved = SavedProof::new(proof, program_info.program_hash(), &output, 5);
+    saved.save(&proof_path, &outputs_path(&proof_path)).unwrap();
+
+    // the outputs are found next to the proof
+    let loaded = SavedProof::load(Some(&proof_path), None).unwrap();
+    assert_eq!(loaded.proof, saved.proof);
+    assert_eq!(loaded.outputs.stack_output[0], 3);
+    assert_eq!(
+        loaded.outputs.program_hash,
+        program_info.program_hash().to_string()
+    );
+
+    // the playground returns the proof as part of the outputs
+    let mut outputs = loaded.outputs;
+    outputs.proof = Some(saved.proof.to_bytes());
+    let outputs_path = dir.join("playground.json");
+    fs::write(&outputs_path, serde_json::to_string(&outputs).unwrap()).unwrap();
+    let loaded = SavedProof::load(None, Some(&outputs_path)).unwrap();
+    let stack_outputs = loaded.outputs.stack_outputs().unwrap();
+    miden_vm::verify(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        loaded.proof,
+    )
+    .unwrap();
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_verify_proof_with_custom_params() {
+    use crate::proving_options::{HashFunctionArg, ProofParams};
+    use miden_examples_core::MidenProgram;
+    use miden_vm::DefaultHost;
+
+    // parameters which match none of the presets accepted by miden_vm::verify
+    let mut params = ProofParams::with_96_bit_security(false);
+    params.num_queries = 40;
+    params.blowup_factor = 16;
+    params.hash_fn = HashFunctionArg::Rpo256;
+
+    let mut program = MidenProgram::new("begin push.1 push.2 add swap drop end", false);
+    program.compile_program().unwrap();
+    let program_info = program.program_info.clone().unwrap();
+    let (output, proof) = miden_vm::prove(
+        &program.program.unwrap(),
+        StackInputs::default(),
+        &mut DefaultHost::default(),
+        params.to_proving_options(),
+    )
+    .unwrap();
+
+    // the proof survives the round trip through the files written by `prove`
+    let dir = std::env::temp_dir().join(format!("miden-custom-proof-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let proof_path = dir.join("add.proof");
+    SavedProof::new(proof, program_info.program_hash(), &output, 5)
+        .save(&proof_path, &outputs_path(&proof_path))
+        .unwrap();
+    let loaded = SavedProof::load(Some(&proof_path), None).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+
+    let stack_outputs = loaded.outputs.stack_outputs().unwrap();
+    let security_level = verify_proof(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        loaded.proof.clone(),
+        MIN_SECURITY_LEVEL,
+    )
+    .unwrap();
+    assert!(security_level >= MIN_SECURITY_LEVEL);
+
+    // the same proof is rejected if more security is required than it provides
+    let err = verify_proof(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        loaded.proof,
+        security_level + 1,
+    )
+    .unwrap_err();
+    assert!(err.contains("conjectured security"), "{err}");
+}
@@ -0,0 +1,20 @@
+//! Input, output and program handling shared by the benchmarking CLI and the WASM playground, so
+//! that an input file behaves identically in the terminal and in the browser.
+
+#![no_std]
+
+extern crate alloc;
+
+mod utils_input;
+mod utils_program;
+mod utils_validation;
+
+pub use utils_input::{
+    parse_word, InputFile, Inputs, MerkleData, MerkleRoot, MerkleStoreEntry, Outputs,
+    SparseMerkleTreeData,
+};
+pub use utils_program::{
+    CompileError, CompileErrorKind, CompileErrorLabel, MidenProgram, SourceLocation, DEBUG_OFF,
+    DEBUG_ON,
+};
+pub use utils_validation::{validate_inputs, ValidationError};
@@ -0,0 +1,877 @@
+use crate::utils_validation::deserialize_input_file;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use miden_crypto::merkle::{Mmr, Smt};
+use miden_vm::{
+    crypto::{
+        MerklePath, MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, Rpo256, RpoDigest,
+    },
+    math::{Felt, StarkField},
+    AdviceInputs, MemAdviceProvider, StackInputs, StackOutputs, Word, ZERO,
+};
+use serde::{Deserialize, Serialize};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Depth of the sparse merkle trees which do not specify a depth.
+const SIMPLE_SMT_DEPTH: u8 = u64::BITS as u8;
+
+/// Minimum depth of a sparse merkle tree.
+const SIMPLE_SMT_MIN_DEPTH: u8 = 1;
+
+/// Number of elements of the stack at the end of a program.
+const STACK_OUTPUT_LEN: usize = 16;
+
+/// Advice map as expected by the advice provider, keyed by digests.
+type AdviceMap = BTreeMap<RpoDigest, Vec<Felt>>;
+
+// OUTPUTS
+// ================================================================================================
+
+/// The Outputs struct is used to serialize the output of the program.
+/// Via Rust WASM we cannot return arbitrary structs, so we need to serialize it to JSON.
+/// Here we need the Outputs because they can be inputs for the verifier.
+///
+/// The same struct is written by the CLI and returned by the playground, so that outputs saved by
+/// either of them can be verified by the other.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Outputs {
+    /// Hash of the program which produced the outputs, empty if unknown.
+    #[serde(default)]
+    pub program_hash: String,
+    /// Stack at the end of the execution, the top of the stack first. The verifier pads it with
+    /// zeros to the 16 elements of the stack, so only trailing zeros may be left out.
+    pub stack_output: Vec<u64>,
+    /// Addresses of the overflow table at the end of the execution, written by older versions of
+    /// the VM. The VM now rejects programs which end with more than 16 elements on the stack, so
+    /// that `stack_output` is the complete stack and this list is empty.
+    #[serde(default)]
+    pub overflow_addrs: Vec<u64>,
+    /// Number of cycles the program ran for, i.e. the length of the main trace before padding, if
+    /// known.
+    pub cycles: Option<usize>,
+    /// Length of the execution trace, if known.
+    pub trace_len: Option<usize>,
+    /// Serialized execution proof, if the program was proven.
+    pub proof: Option<Vec<u8>>,
+    /// Roots of the merkle data of the inputs, see [MerkleRoot].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub merkle_roots: BTreeMap<String, String>,
+}
+
+impl Outputs {
+    /// Returns the stack outputs the verifier checks the proof against.
+    pub fn stack_outputs(&self) -> Result<StackOutputs, String> {
+        if !self.overflow_addrs.is_empty() {
+            return Err(format!(
+                "invalid stack output - the stack overflows by {} elements, but the VM only \
+                 accepts {STACK_OUTPUT_LEN} elements at the end of a program",
+                self.overflow_addrs.len()
+            ));
+        }
+        StackOutputs::try_from_ints(self.stack_output.iter().copied())
+            .map_err(|e| format!("invalid stack output - {e}"))
+    }
+
+    /// Keeps the top `num_outputs` elements of the stack output. The outputs then only verify if
+    /// the elements left out are zeros.
+    pub fn truncate_stack(&mut self, num_outputs: usize) {
+        self.stack_output.truncate(num_outputs);
+    }
+}
+
+// MERKLE DATA
+// ================================================================================================
+
+/// Prefix of a reference to the root of merkle data in the stacks and advice map keys of an
+/// input file, e.g. `root:accounts`.
+const MERKLE_ROOT_PREFIX: &str = "root:";
+
+/// Struct used to deserialize an entry of the merkle store from input file. The entry holds the
+/// merkle data and an optional name, e.g. `{"name": "accounts", "merkle_tree": [...]}`.
+#[derive(Deserialize)]
+pub struct MerkleStoreEntry {
+    /// Optional name of the merkle data, used to reference its root as `root:<name>`.
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub data: MerkleData,
+}
+
+impl MerkleStoreEntry {
+    /// Returns the name of the entry, or `merkle_store[<index>]` if it has no name.
+    pub fn name(&self, index: usize) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("merkle_store[{index}]"))
+    }
+}
+
+/// Struct used to deserialize merkle data from input file. Merkle data can be represented as a
+/// merkle tree, a sparse merkle tree, a merkle mountain range, a partial merkle tree or a compact
+/// sparse merkle tree.
+#[derive(Deserialize)]
+pub enum MerkleData {
+    /// String representation of a merkle tree.  The merkle tree is represented as a vector of
+    /// 32 byte hex strings where each string represents a leaf in the tree.
+    #[serde(rename = "merkle_tree")]
+    MerkleTree(Vec<String>),
+    /// String representation of a sparse merkle tree, see [SparseMerkleTreeData].
+    #[serde(rename = "sparse_merkle_tree")]
+    SparseMerkleTree(SparseMerkleTreeData),
+    /// String representation of a merkle mountain range. The merkle mountain range is represented
+    /// as a vector of 32 byte hex strings where each string represents a leaf in the order the
+    /// leaves are added.
+    #[serde(rename = "mmr")]
+    Mmr(Vec<String>),
+    /// String representation of a partial merkle tree. The partial merkle tree is represented as
+    /// a vector of merkle paths, each of them a tuple consisting of a u64 leaf index, a 32 byte
+    /// hex string representing the value of the leaf and a vector of 32 byte hex strings
+    /// representing the siblings on the path from the leaf to the root.
+    #[serde(rename = "partial_merkle_tree")]
+    PartialMerkleTree(Vec<(u64, String, Vec<String>)>),
+    /// String representation of a compact sparse merkle tree as used by Miden accounts. The tree
+    /// is represented as a vector of tuples where each tuple consists of a 32 byte hex string
+    /// representing the key and a 32 byte hex string representing the value.
+    #[serde(rename = "smt")]
+    Smt(Vec<(String, String)>),
+}
+
+/// String representation of a sparse merkle tree. The entries of the tree are represented as a
+/// vector of tuples where each tuple consists of a u64 leaf index and a 32 byte hex string
+/// representing the value of the leaf.
+///
+/// The tree is either given by its entries alone, in which case it is 64 levels deep, or as an
+/// object with a `depth` between 1 and 64 and the `entries`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum SparseMerkleTreeData {
+    Entries(Vec<(u64, String)>),
+    WithDepth {
+        depth: u8,
+        entries: Vec<(u64, String)>,
+    },
+}
+
+/// Root of merkle data which was loaded into the merkle store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleRoot {
+    /// Name of the merkle data, or `merkle_store[<index>]` if the data has no name.
+    pub name: String,
+    /// Root of the merkle data. For a merkle mountain range this is the hash of its peaks.
+    pub root: RpoDigest,
+}
+
+impl MerkleData {
+    /// Returns the name of the kind of the merkle data in the input file, e.g. `merkle_tree`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MerkleTree(_) => "merkle_tree",
+            Self::SparseMerkleTree(_) => "sparse_merkle_tree",
+            Self::Mmr(_) => "mmr",
+            Self::PartialMerkleTree(_) => "partial_merkle_tree",
+            Self::Smt(_) => "smt",
+        }
+    }
+
+    /// Adds the nodes of the merkle data to the merkle store and the advice map entries the
+    /// standard library expects for it to the advice map. Returns the root of the merkle data.
+    pub(crate) fn add_to_store(
+        &self,
+        merkle_store: &mut MerkleStore,
+        advice_map: &mut AdviceMap,
+    ) -> Result<RpoDigest, String> {
+        let root = match self {
+            Self::MerkleTree(data) => {
+                let leaves = parse_merkle_tree(data)?;
+                let merkle_tree = MerkleTree::new(leaves)
+                    .map_err(|e| format!("failed to add merkle tree to merkle store - {e}"))?;
+                merkle_store.extend(merkle_tree.inner_nodes());
+                merkle_tree.root()
+            }
+            Self::SparseMerkleTree(data) => {
+                let entries = parse_sparse_merkle_tree(data.entries())?;
+                add_sparse_merkle_tree(merkle_store, data.depth(), entries).map_err(|e| {
+                    format!("failed to add sparse merkle tree to merkle store - {e}")
+                })?
+            }
+            Self::Mmr(data) => {
+                let leaves = parse_merkle_tree(data)?;
+                let mmr = Mmr::from(leaves.into_iter().map(RpoDigest::from));
+                merkle_store.extend(mmr.inner_nodes());
+
+                // `std::collections::mmr::unpack` reads the peaks from the advice map
+                let peaks = mmr.peaks();
+                let mut values = vec![Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO];
+                values.extend(peaks.flatten_and_pad_peaks());
+                advice_map.insert(peaks.hash_peaks(), values);
+                peaks.hash_peaks()
+            }
+            Self::PartialMerkleTree(data) => {
+                let paths = parse_merkle_paths(data)?;
+                let pmt = PartialMerkleTree::with_paths(paths).map_err(|e| {
+                    format!("failed to add partial merkle tree to merkle store - {e}")
+                })?;
+                merkle_store.extend(pmt.inner_nodes());
+                pmt.root()
+            }
+            Self::Smt(data) => {
+                let entries = parse_smt(data)?;
+                let smt = Smt::with_entries(entries).map_err(|e| {
+                    format!("failed to add compact sparse merkle tree to merkle store - {e}")
+                })?;
+                merkle_store.extend(smt.inner_nodes());
+
+                // `std::collections::smt::get` reads the leaves from the advice map
+                advice_map.extend(
+                    smt.leaves()
+                        .map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+                );
+                smt.root()
+            }
+        };
+
+        Ok(root)
+    }
+}
+
+impl SparseMerkleTreeData {
+    /// Returns the depth of the tree.
+    pub fn depth(&self) -> u8 {
+        match self {
+            Self::Entries(_) => SIMPLE_SMT_DEPTH,
+            Self::WithDepth { depth, .. } => *depth,
+        }
+    }
+
+    /// Returns the entries of the tree.
+    pub fn entries(&self) -> &[(u64, String)] {
+        match self {
+            Self::Entries(entries) | Self::WithDepth { entries, .. } => entries,
+        }
+    }
+}
+
+// INPUT FILE
+// ================================================================================================
+
+// TODO consider using final types instead of string representations.
+/// Input file struct that is used to deserialize input data from file. It consists of four
+/// components:
+/// - operand_stack
+/// - advice_stack
+/// - advice_map
+/// - merkle_store
+///
+/// The root of merkle data in the merkle store can be referenced as `root:<name>` in the operand
+/// stack, the advice stack and the keys of the advice map, where `<name>` is the name of the data
+/// or `merkle_store[<index>]` if it has no name. In the stacks the reference is expanded to the
+/// four elements of the root.
+#[derive(Deserialize)]
+pub struct InputFile {
+    /// String representation of the initial operand stack, composed of chained field elements.
+    pub operand_stack: Option<Vec<String>>,
+    /// Optional string representation of the initial advice stack, composed of chained field
+    /// elements.
+    pub advice_stack: Option<Vec<String>>,
+    /// Optional map of 32 byte hex strings to vectors of u64s representing the initial advice map.
+    pub advice_map: Option<BTreeMap<String, Vec<u64>>>,
+    /// Optional vector of merkle data which will be loaded into the initial merkle store. Merkle
+    /// data is represented as 32 byte hex strings and node indexes are represented as u64s. The
+    /// advice map entries which the standard library expects for merkle mountain ranges and
+    /// compact sparse merkle trees are added to the advice map.
+    pub merkle_store: Option<Vec<MerkleStoreEntry>>,
+}
+
+/// Helper methods to interact with the input file
+impl InputFile {
+    /// Parse advice provider data from the input file. Returns the advice provider together with
+    /// the roots of the merkle data in its merkle store.
+    pub fn parse_advice_provider(&self) -> Result<(MemAdviceProvider, Vec<MerkleRoot>), String> {
+        let mut advice_inputs = AdviceInputs::default();
+
+        let (merkle_store, map, roots) = self
+            .parse_merkle_store()
+            .map_err(|e| format!("failed to parse advice provider: {e}"))?;
+        advice_inputs = advice_inputs.with_merkle_store(merkle_store).with_map(map);
+
+        let stack = self
+            .parse_advice_stack(&roots)
+            .map_err(|e| format!("failed to parse advice provider: {e}"))?;
+        advice_inputs = advice_inputs
+            .with_stack_values(stack)
+            .map_err(|e| e.to_string())?;
+
+        // entries of the advice map in the input file take precedence over the entries added
+        // for the merkle data
+        if let Some(map) = self
+            .parse_advice_map(&roots)
+            .map_err(|e| format!("failed to parse advice provider: {e}"))?
+        {
+            advice_inputs = advice_inputs.with_map(map);
+        }
+
+        Ok((MemAdviceProvider::from(advice_inputs), roots))
+    }
+
+    /// Parse advice stack data from the input file.
+    fn parse_advice_stack(&self, roots: &[MerkleRoot]) -> Result<Vec<u64>, String> {
+        let mut stack = Vec::new();
+        for v in self.advice_stack.as_deref().unwrap_or(&[]) {
+            let values = parse_stack_value(v, roots)
+                .map_err(|e| format!("failed to parse advice stack value `{v}` - {e}"))?;
+            stack.extend(values);
+        }
+        Ok(stack)
+    }
+
+    /// Parse advice map data from the input file.
+    fn parse_advice_map(&self, roots: &[MerkleRoot]) -> Result<Option<AdviceMap>, String> {
+        let advice_map = match &self.advice_map {
+            Some(advice_map) => advice_map,
+            None => return Ok(None),
+        };
+
+        let map = advice_map
+            .iter()
+            .map(|(k, v)| {
+                let key = parse_advice_map_key(k, roots)?;
+                let values = v
+                    .iter()
+                    .map(|v| parse_advice_map_value(*v))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((key, values))
+            })
+            .collect::<Result<AdviceMap, String>>()?;
+
+        Ok(Some(map))
+    }
+
+    /// Parse merkle store data from the input file. Returns the merkle store together with the
+    /// advice map entries and the roots of the merkle data.
+    fn parse_merkle_store(&self) -> Result<(MerkleStore, AdviceMap, Vec<MerkleRoot>), String> {
+        let mut merkle_store = MerkleStore::default();
+        let mut advice_map = AdviceMap::new();
+        let mut roots: Vec<MerkleRoot> = Vec::new();
+        for (i, entry) in self.merkle_store.iter().flatten().enumerate() {
+            let root = entry
+                .data
+                .add_to_store(&mut merkle_store, &mut advice_map)?;
+
+            let name = entry.name(i);
+            if roots.iter().any(|root| root.name == name) {
+                return Err(format!("duplicate merkle data name `{name}`"));
+            }
+            roots.push(MerkleRoot { name, root });
+        }
+
+        Ok((merkle_store, advice_map, roots))
+    }
+
+    /// Parse and return the stack inputs for the program. The `roots` of the merkle data are
+    /// returned by [InputFile::parse_advice_provider].
+    pub fn parse_stack_inputs(&self, roots: &[MerkleRoot]) -> Result<StackInputs, String> {
+        let operand_stack = match self.operand_stack.as_ref() {
+            Some(operand_stack) => operand_stack,
+            None => return Ok(StackInputs::default()),
+        };
+
+        let mut stack_inputs = Vec::new();
+        for v in operand_stack {
+            let values = parse_stack_value(v, roots)
+                .map_err(|e| format!("failed to parse operand stack value `{v}` - {e}"))?;
+            stack_inputs.extend(values);
+        }
+
+        StackInputs::try_from_ints(stack_inputs).map_err(|e| e.to_string())
+    }
+}
+
+// INPUTS
+// ================================================================================================
+
+/// Miden Inputs plus Outputs that are used as inputs for the verifier.
+#[derive(Default)]
+pub struct Inputs {
+    pub stack_inputs: StackInputs,
+    pub advice_provider: MemAdviceProvider,
+    pub stack_outputs: StackOutputs,
+    /// Roots of the merkle data in the merkle store, in the order of the input file.
+    pub merkle_roots: Vec<MerkleRoot>,
+}
+
+impl Inputs {
+    /// Returns empty inputs, i.e. an empty operand stack and an empty advice provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the content of an inputs file. Empty content leaves the inputs empty.
+    ///
+    /// If the file is invalid, the error lists the invalid values together with their JSON paths,
+    /// see [InputFile::validate].
+    pub fn deserialize_inputs(&mut self, inputs: &str) -> Result<(), String> {
+        if !inputs.trim().is_empty() {
+            // the input file is only deserialized with the paths of its values once the fast
+            // deserialization failed, to locate the error
+            let inputs_des: InputFile = serde_json::from_str(inputs)
+                .or_else(|_| deserialize_input_file(inputs).map_err(|e| e.to_string()))?;
+
+            self.parse_input_file(&inputs_des).map_err(|err| {
+                // the input file is only validated once parsing failed, to keep parsing fast
+                let errors = inputs_des.validate();
+                if errors.is_empty() {
+                    return err;
+                }
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Parses the inputs of a deserialized input file.
+    fn parse_input_file(&mut self, input_file: &InputFile) -> Result<(), String> {
+        let (advice_provider, merkle_roots) = input_file.parse_advice_provider()?;
+        self.stack_inputs = input_file.parse_stack_inputs(&merkle_roots)?;
+        self.advice_provider = advice_provider;
+        self.merkle_roots = merkle_roots;
+        Ok(())
+    }
+
+    /// Returns the roots of the merkle data as hex strings keyed by the names of the data, as
+    /// reported in [Outputs].
+    pub fn merkle_roots_hex(&self) -> BTreeMap<String, String> {
+        self.merkle_roots
+            .iter()
+            .map(|root| (root.name.clone(), root.root.to_string()))
+            .collect()
+    }
+
+    /// Parses serialized [Outputs] and stores their stack as the stack outputs for the verifier.
+    pub fn deserialize_outputs(&mut self, outputs_as_str: &str) -> Result<(), String> {
+        let outputs_as_json: Outputs =
+            serde_json::from_str(outputs_as_str).map_err(|e| e.to_string())?;
+
+        self.stack_outputs = outputs_as_json.stack_outputs()?;
+
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Parse a value of the operand or advice stack. A value is one of:
+/// - a field element in decimal, e.g. `42`, or in hex, e.g. `0x2a`
+/// - `u64:<value>`, a u64 which is split into its low and high 32-bit limbs
+/// - `word:<hex>`, a 32 byte hex string which is expanded to the four elements of the word
+/// - `str:<text>`, a string whose UTF-8 bytes are packed into elements of 4 bytes each, in little
+///   endian order
+/// - `root:<name>`, a reference to the root of merkle data, which is expanded to the four elements
+///   of the root
+///
+/// The elements of a value are returned in order, so its last element ends up on top of the
+/// operand stack, e.g. the high limb of a u64 as expected by `std::math::u64`. Elements read from
+/// the advice stack with `adv_push` end up in the same order.
+pub(crate) fn parse_stack_value(value: &str, roots: &[MerkleRoot]) -> Result<Vec<u64>, String> {
+    if let Some(name) = value.strip_prefix(MERKLE_ROOT_PREFIX) {
+        let root = find_merkle_root(name, roots)?;
+        Ok(Word::from(root).iter().map(Felt::as_int).collect())
+    } else if let Some(v) = value.strip_prefix("u64:") {
+        let v = parse_u64(v)?;
+        Ok(vec![v & u64::from(u32::MAX), v >> 32])
+    } else if let Some(word_hex) = value.strip_prefix("word:") {
+        let word = parse_word(word_hex.strip_prefix("0x").unwrap_or(word_hex))?;
+        Ok(word.iter().map(Felt::as_int).collect())
+    } else if let Some(text) = value.strip_prefix("str:") {
+        Ok(text
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                let mut bytes = [0u8; 4];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                u64::from(u32::from_le_bytes(bytes))
+            })
+            .collect())
+    } else {
+        let v = parse_u64(value)?;
+        if v >= Felt::MODULUS {
+            return Err(format!(
+                "{v} is not a field element, it must be less than the field modulus {}",
+                Felt::MODULUS
+            ));
+        }
+        Ok(vec![v])
+    }
+}
+
+/// Parse a u64 in decimal or, with the 0x prefix, in hex.
+fn parse_u64(value: &str) -> Result<u64, String> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    }
+    .map_err(|e| format!("`{value}` is not a valid u64 - {e}"))
+}
+
+/// Returns the root of the merkle data with the given name.
+fn find_merkle_root(name: &str, roots: &[MerkleRoot]) -> Result<RpoDigest, String> {
+    roots
+        .iter()
+        .find(|root| root.name == name)
+        .map(|root| root.root)
+        .ok_or_else(|| format!("unknown merkle data `{name}`"))
+}
+
+/// Parse and return merkle tree leaves.
+fn parse_merkle_tree(tree: &[String]) -> Result<Vec<Word>, String> {
+    tree.iter()
+        .map(|v| {
+            let leaf = parse_word(v)?;
+            Ok(leaf)
+        })
+        .collect()
+}
+
+/// Parse and return sparse merkle tree entries.
+fn parse_sparse_merkle_tree(tree: &[(u64, String)]) -> Result<Vec<(u64, Word)>, String> {
+    tree.iter()
+        .map(|(index, v)| {
+            let leaf = parse_word(v)?;
+            Ok((*index, leaf))
+        })
+        .collect()
+}
+
+/// Parse and return the merkle paths of a partial merkle tree.
+fn parse_merkle_paths(
+    paths: &[(u64, String, Vec<String>)],
+) -> Result<Vec<(u64, RpoDigest, MerklePath)>, String> {
+    paths
+        .iter()
+        .map(|(index, v, path)| {
+            let leaf = parse_word(v)?;
+            let path = path
+                .iter()
+                .map(|node| parse_word(node).map(RpoDigest::from))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((*index, leaf.into(), MerklePath::new(path)))
+        })
+        .collect()
+}
+
+/// Parse and return compact sparse merkle tree entries.
+fn parse_smt(tree: &[(String, String)]) -> Result<Vec<(RpoDigest, Word)>, String> {
+    tree.iter()
+        .map(|(k, v)| {
+            let key = parse_word(k)?;
+            let value = parse_word(v)?;
+            Ok((key.into(), value))
+        })
+        .collect()
+}
+
+/// Adds the nodes of a sparse merkle tree of the given depth to the merkle store and returns the
+/// root of the tree.
+///
+/// The store already holds the roots of all empty subtrees, so the tree is built by setting its
+/// leaves one by one, starting from the root of the empty tree.
+fn add_sparse_merkle_tree(
+    merkle_store: &mut MerkleStore,
+    depth: u8,
+    entries: Vec<(u64, Word)>,
+) -> Result<RpoDigest, String> {
+    check_sparse_merkle_tree_depth(depth)?;
+
+    let mut root = (0..depth).fold(RpoDigest::default(), |node, _| Rpo256::merge(&[node, node]));
+    let mut indexes = BTreeSet::new();
+    for (index, leaf) in entries {
+        if !indexes.insert(index) {
+            return Err(format!("duplicate values for index {index}"));
+        }
+        let node_index = sparse_merkle_tree_index(depth, index)?;
+        root = merkle_store
+            .set_node(root, node_index, leaf.into())
+            .map_err(|e| e.to_string())?
+            .root;
+    }
+
+    Ok(root)
+}
+
+/// Checks that a sparse merkle tree of the given depth can be built.
+pub(crate) fn check_sparse_merkle_tree_depth(depth: u8) -> Result<(), String> {
+    if !(SIMPLE_SMT_MIN_DEPTH..=SIMPLE_SMT_DEPTH).contains(&depth) {
+        return Err(format!(
+            "depth {depth} is not between {SIMPLE_SMT_MIN_DEPTH} and {SIMPLE_SMT_DEPTH}"
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the node index of a leaf of a sparse merkle tree of the given depth.
+pub(crate) fn sparse_merkle_tree_index(depth: u8, index: u64) -> Result<NodeIndex, String> {
+    NodeIndex::new(depth, index)
+        .map_err(|_| format!("index {index} does not fit into a tree of depth {depth}"))
+}
+
+/// Parse an advice map key, which is either a 32 byte hex string, the 0x prefix is optional, or
+/// a reference to the root of merkle data.
+pub(crate) fn parse_advice_map_key(key: &str, roots: &[MerkleRoot]) -> Result<RpoDigest, String> {
+    match key.strip_prefix(MERKLE_ROOT_PREFIX) {
+        Some(name) => find_merkle_root(name, roots),
+        None => {
+            let key_hex = format!("0x{}", key.strip_prefix("0x").unwrap_or(key));
+            RpoDigest::try_from(key_hex.as_str())
+                .map_err(|e| format!("failed to decode advice map key `{key}` - {e}"))
+        }
+    }
+}
+
+/// Parse a value of the advice map.
+pub(crate) fn parse_advice_map_value(value: u64) -> Result<Felt, String> {
+    Felt::try_from(value)
+        .map_err(|e| format!("failed to convert advice map value `{value}` to Felt - {e}"))
+}
+
+/// Parse a `Word` from a hex string.
+pub fn parse_word(word_hex: &str) -> Result<Word, String> {
+    let mut word_data = [0u8; 32];
+    hex::decode_to_slice(word_hex, &mut word_data)
+        .map_err(|e| format!("failed to decode `Word` from hex {word_hex} - {e}"))?;
+    let mut word = Word::default();
+    for (i, value) in word_data.chunks(8).enumerate() {
+        word[i] = Felt::try_from(value).map_err(|e| {
+            format!("failed to convert `Word` data {word_hex} (element {i}) to Felt - {e}")
+        })?;
+    }
+    Ok(word)
+}
+
+#[test]
+fn test_parse_output() {
+    let output_str: &str = r#"
+    {
+        "stack_output": [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        "trace_len": 1024
+    }"#;
+
+    let mut inputs: Inputs = Inputs::new();
+    inputs.deserialize_outputs(output_str).unwrap();
+
+    let output: StackOutputs = inputs.stack_outputs;
+
+    assert_eq!(
+        output.as_int_vec(),
+        [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_parse_inputs() {
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(r#"{"operand_stack": ["1", "2"], "advice_stack": ["3"]}"#)
+        .unwrap();
+    // the last value of the operand stack ends up on top of the stack
+    assert_eq!(inputs.stack_inputs[0], Felt::new(2));
+    assert_eq!(inputs.stack_inputs[1], Felt::new(1));
+
+    // a malformed advice map key is reported instead of panicking
+    let err = Inputs::new()
+        .deserialize_inputs(r#"{"advice_map": {"zz": [1]}}"#)
+        .unwrap_err();
+    assert!(
+        err.contains("failed to decode advice map key `zz`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parse_sparse_merkle_tree() {
+    use miden_vm::crypto::SimpleSmt;
+
+    let entries = [(
+        1,
+        "0100000000000000000000000000000000000000000000000000000000000000".to_string(),
+    )];
+    let leaves = parse_sparse_merkle_tree(&entries).unwrap();
+
+    // the trees are identical to the ones built by `SimpleSmt`
+    let mut store = MerkleStore::default();
+    let root = add_sparse_merkle_tree(&mut store, 8, leaves.clone()).unwrap();
+    assert_eq!(
+        root,
+        SimpleSmt::<8>::with_leaves(leaves.clone()).unwrap().root()
+    );
+    let root = add_sparse_merkle_tree(&mut store, 64, leaves.clone()).unwrap();
+    assert_eq!(root, SimpleSmt::<64>::with_leaves(leaves).unwrap().root());
+
+    // the depth is optional and leaves must fit into the tree
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(&format!(r#"{{"merkle_store": [{{"sparse_merkle_tree": {{"depth": 1, "entries": [[1, "{0}"]]}}}}, {{"sparse_merkle_tree": [[1, "{0}"]]}}]}}"#, entries[0].1))
+        .unwrap();
+    let err = Inputs::new()
+        .deserialize_inputs(&format!(r#"{{"merkle_store": [{{"sparse_merkle_tree": {{"depth": 1, "entries": [[2, "{}"]]}}}}]}}"#, entries[0].1))
+        .unwrap_err();
+    assert!(
+        err.contains("index 2 does not fit into a tree of depth 1"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parse_merkle_structures() {
+    use crate::MidenProgram;
+
+    let to_hex = |word: Word| {
+        word.iter()
+            .map(|felt| format!("{:016x}", felt.as_int().swap_bytes()))
+            .collect::<String>()
+    };
+    let run = |code: &str, inputs_file: &str, stack: Vec<u64>, num_outputs: usize| {
+        let mut inputs = Inputs::new();
+        inputs.deserialize_inputs(inputs_file).unwrap();
+        let mut program = MidenProgram::new(code, false);
+        program.compile_program().unwrap();
+        let mut host = MidenProgram::new_host(inputs.advice_provider).unwrap();
+        let stack_inputs = StackInputs::try_from_ints(stack).unwrap();
+        let trace = miden_vm::execute(
+            program.program.as_ref().unwrap(),
+            stack_inputs,
+            &mut host,
+            Default::default(),
+        )
+        .unwrap();
+        trace.stack_outputs().stack_truncated(num_outputs).to_vec()
+    };
+    let words: Vec<Word> = (1..=3u64)
+        .map(|i| [Felt::new(i), Felt::new(i + 10), ZERO, Felt::new(i * 7)])
+        .collect();
+
+    // the leaves of a merkle mountain range can be read with `std::collections::mmr`
+    let mmr = Mmr::from(words.iter().map(|w| RpoDigest::from(*w)));
+    let leaves = words.iter().map(|w| format!(r#""{}""#, to_hex(*w)));
+    let inputs_file = format!(
+        r#"{{"merkle_store": [{{"mmr": [{}]}}]}}"#,
+        leaves.collect::<Vec<_>>().join(", ")
+    );
+    let mut stack = vec![1000];
+    stack.extend(
+        Word::from(mmr.peaks().hash_peaks())
+            .iter()
+            .map(Felt::as_int),
+    );
+    let code = "
+        use.std::collections::mmr
+        begin
+            exec.mmr::unpack push.1000 push.2 exec.mmr::get swapw dropw
+        end";
+    let leaf = run(code, &inputs_file, stack, 4);
+    assert_eq!(leaf, words[2].iter().rev().copied().collect::<Vec<_>>());
+
+    // the values of a compact sparse merkle tree can be read with `std::collections::smt`
+    let entries: Vec<_> = words.iter().map(|w| (RpoDigest::from(*w), *w)).collect();
+    let smt = Smt::with_entries(entries).unwrap();
+    let entries = words
+        .iter()
+        .map(|w| format!(r#"["{0}", "{0}"]"#, to_hex(*w)));
+    let inputs_file = format!(
+        r#"{{"merkle_store": [{{"smt": [{}]}}]}}"#,
+        entries.collect::<Vec<_>>().join(", ")
+    );
+    let mut stack: Vec<u64> = Word::from(smt.root()).iter().map(Felt::as_int).collect();
+    stack.extend(words[1].iter().map(Felt::as_int));
+    let code = "
+        use.std::collections::smt
+        begin
+            exec.smt::get
+        end";
+    let value = run(code, &inputs_file, stack, 4);
+    assert_eq!(value, words[1].iter().rev().copied().collect::<Vec<_>>());
+
+    // a partial merkle tree is built from the paths of its leaves
+    let tree = MerkleTree::new([words[0], words[1], words[2], [ZERO; 4]]).unwrap();
+    let path = tree.get_path(NodeIndex::new(2, 1).unwrap()).unwrap();
+    let path = path
+        .iter()
+        .map(|node| format!(r#""{}""#, to_hex((*node).into())));
+    let inputs_file = format!(
+        r#"{{"merkle_store": [{{"partial_merkle_tree": [[1, "{}", [{}]]]}}]}}"#,
+        to_hex(words[1]),
+        path.collect::<Vec<_>>().join(", ")
+    );
+    let mut stack: Vec<u64> = Word::from(tree.root()).iter().map(Felt::as_int).collect();
+    stack.extend([1, 2]);
+    let code = "
+        begin
+            mtree_get swapw dropw
+        end";
+    let value = run(code, &inputs_file, stack, 4);
+    assert_eq!(value, words[1].iter().rev().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_parse_merkle_roots() {
+    let leaf = "0100000000000000000000000000000000000000000000000000000000000000";
+    let tree = MerkleTree::new([parse_word(leaf).unwrap(), Word::default()]).unwrap();
+    let root: Vec<u64> = Word::from(tree.root()).iter().map(Felt::as_int).collect();
+
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(&format!(
+            r#"{{
+                "operand_stack": ["root:tree", "7"],
+                "advice_stack": ["root:merkle_store[1]"],
+                "advice_map": {{"root:tree": [1]}},
+                "merkle_store": [
+                    {{"name": "tree", "merkle_tree": ["{leaf}", "{0}"]}},
+                    {{"merkle_tree": ["{leaf}", "{0}"]}}
+                ]
+            }}"#,
+            "0".repeat(64)
+        ))
+        .unwrap();
+    assert_eq!(inputs.merkle_roots[0].name, "tree");
+    assert_eq!(inputs.merkle_roots[0].root, tree.root());
+    assert_eq!(inputs.merkle_roots[1].name, "merkle_store[1]");
+
+    // the root is expanded to its four elements, the last one ends up on top of the stack
+    let stack: Vec<u64> = inputs.stack_inputs.iter().map(Felt::as_int).collect();
+    assert_eq!(stack[..5], [7, root[3], root[2], root[1], root[0]]);
+
+    let err = Inputs::new()
+        .deserialize_inputs(r#"{"operand_stack": ["root:missing"]}"#)
+        .unwrap_err();
+    assert!(err.contains("unknown merkle data `missing`"), "{err}");
+}
+
+#[test]
+fn test_parse_typed_values() {
+    let mut inputs = Inputs::new();
+    inputs
+        .deserialize_inputs(
+            r#"{
+                "operand_stack": ["str:hi", "u64:0x100000002", "0x10"],
+                "advice_stack": ["word:0100000000000000020000000000000003000000000000000400000000000000"]
+            }"#,
+        )
+        .unwrap();
+    let stack: Vec<u64> = inputs.stack_inputs.iter().map(Felt::as_int).collect();
+    assert_eq!(stack[..4], [16, 1, 2, 0x6968]);
+
+    let err = Inputs::new()
+        .deserialize_inputs(r#"{"operand_stack": ["18446744069414584321"]}"#)
+        .unwrap_err();
+    assert!(err.contains("must be less than the field modulus"), "{err}");
+}
@@ -0,0 +1,267 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use miden_stdlib::StdLibrary;
+use miden_vm::{
+    diagnostics::{miette::SourceSpan, Diagnostic, LabeledSpan, Report, SourceCode},
+    Assembler, AssemblyError, DefaultHost, Kernel, MemAdviceProvider, Program, ProgramInfo,
+};
+use serde::{Deserialize, Serialize};
+
+pub const DEBUG_OFF: bool = false;
+pub const DEBUG_ON: bool = true;
+
+pub struct MidenProgram {
+    pub assembler: Assembler,
+    pub masm_code: String,
+    pub debug: bool,
+    pub program: Option<Program>,
+    pub program_info: Option<ProgramInfo>,
+}
+
+impl MidenProgram {
+    pub fn new<S: AsRef<str>>(code_as_str: S, debug: bool) -> Self {
+        Self {
+            assembler: Assembler::default(),
+            masm_code: code_as_str.as_ref().to_string(),
+            debug,
+            program: None,
+            program_info: None,
+        }
+    }
+
+    /// Returns a new assembler with the standard library loaded. In debug mode the assembler
+    /// attaches debug decorators to the compiled program.
+    pub fn new_assembler(debug: bool) -> Result<Assembler, String> {
+        Ok(Assembler::default()
+            .with_library(StdLibrary::default())
+            .map_err(|err| format!("Failed to load stdlib - {}", err))?
+            .with_debug_mode(debug))
+    }
+
+    /// Returns a new host for executing programs with the provided advice. The host provides the
+    /// procedures of the standard library, which compiled programs call into.
+    pub fn new_host(
+        advice_provider: MemAdviceProvider,
+    ) -> Result<DefaultHost<MemAdviceProvider>, String> {
+        let mut host = DefaultHost::new(advice_provider);
+        host.load_mast_forest(StdLibrary::default().mast_forest().clone())
+            .map_err(|err| format!("Failed to load stdlib - {}", err))?;
+        Ok(host)
+    }
+
+    /// Compiles the program with a freshly created assembler (cold compilation).
+    pub fn compile_program(&mut self) -> Result<(), CompileError> {
+        self.assembler = Self::new_assembler(self.debug)
+            .map_err(|message| CompileError::new(CompileErrorKind::Assembly, message))?;
+        self.recompile_program()
+    }
+
+    /// Compiles the program with the assembler created by the previous call to
+    /// [MidenProgram::compile_program] (hot compilation). The assembler keeps the libraries it
+    /// loaded, so these are not loaded again.
+    pub fn recompile_program(&mut self) -> Result<(), CompileError> {
+        self.program = Some(
+            self.assembler
+                .clone()
+                .assemble_program(self.masm_code.as_str())
+                .map_err(CompileError::from_report)?,
+        );
+
+        self.program_info = Some(ProgramInfo::new(
+            self.program
+                .as_ref()
+                .expect("Could not compile program")
+                .hash(),
+            Kernel::default(),
+        ));
+
+        Ok(())
+    }
+}
+
+// COMPILE ERROR
+// ================================================================================================
+
+/// Stage of the compilation at which a [CompileError] was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompileErrorKind {
+    /// The source could not be parsed, e.g. an unknown instruction or an invalid literal.
+    Syntax,
+    /// The source was parsed but is invalid, e.g. it references an undefined procedure.
+    Semantic,
+    /// The program could not be assembled, e.g. it imports a module which does not exist.
+    Assembly,
+}
+
+/// Location of a part of the program source. Lines and columns start at 1, and the length is
+/// given in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub line: u32,
+    pub column: u32,
+    pub length: u32,
+}
+
+/// A part of the program source which a [CompileError] points to, with an optional description.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompileErrorLabel {
+    pub message: Option<String>,
+    pub location: SourceLocation,
+}
+
+/// An error reported by the assembler, with the locations in the program source it refers to, so
+/// that it can be shown next to the offending code.
+///
+/// Semantic errors are reported in batches: the top-level error summarizes them and the errors
+/// themselves are listed in `related`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompileError {
+    pub kind: CompileErrorKind,
+    pub message: String,
+    /// Location of the first label of the error, if it has any.
+    pub location: Option<SourceLocation>,
+    pub labels: Vec<CompileErrorLabel>,
+    pub help: Option<String>,
+    pub related: Vec<CompileError>,
+}
+
+impl CompileError {
+    /// Returns an error which does not point to the program source.
+    pub fn new(kind: CompileErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            location: None,
+            labels: Vec::new(),
+            help: None,
+            related: Vec::new(),
+        }
+    }
+
+    /// Converts a diagnostic report of the assembler into an error.
+    pub fn from_report(report: Report) -> Self {
+        let kind = if report.downcast_ref::<AssemblyError>().is_some() {
+            CompileErrorKind::Assembly
+        } else if report
+            .related()
+            .is_some_and(|mut related| related.next().is_some())
+        {
+            // the semantic analysis rolls up its errors into a single syntax error
+            CompileErrorKind::Semantic
+        } else {
+            CompileErrorKind::Syntax
+        };
+        Self::from_diagnostic(kind, report.as_ref(), None)
+    }
+
+    fn from_diagnostic(
+        kind: CompileErrorKind,
+        diagnostic: &dyn Diagnostic,
+        parent_source: Option<&dyn SourceCode>,
+    ) -> Self {
+        // related diagnostics usually share the source of the diagnostic they are attached to
+        let source = diagnostic.source_code().or(parent_source);
+
+        let labels: Vec<CompileErrorLabel> = diagnostic
+            .labels()
+            .into_iter()
+            .flatten()
+            .filter_map(|label| {
+                Some(CompileErrorLabel {
+                    message: label.label().map(ToString::to_string),
+                    location: source_location(source?, &label)?,
+                })
+            })
+            .collect();
+        let related = diagnostic
+            .related()
+            .into_iter()
+            .flatten()
+            .map(|related| Self::from_diagnostic(kind, related, source))
+            .collect();
+
+        Self {
+            kind,
+            message: diagnostic.to_string(),
+            location: labels.first().map(|label| label.location),
+            labels,
+            help: diagnostic.help().map(|help| help.to_string()),
+            related,
+        }
+    }
+}
+
+/// Returns the line, the column and the length of a labeled span of the source.
+fn source_location(source: &dyn SourceCode, label: &LabeledSpan) -> Option<SourceLocation> {
+    let span: &SourceSpan = label.inner();
+    let contents = source.read_span(span, 0, 0).ok()?;
+    Some(SourceLocation {
+        line: u32::try_from(contents.line() + 1).ok()?,
+        column: u32::try_from(contents.column() + 1).ok()?,
+        length: u32::try_from(span.len()).ok()?,
+    })
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(location) = self.location {
+            write!(f, " at line {}, column {}", location.line, location.column)?;
+        }
+        // the help of a batch of errors only refers to the related errors
+        match &self.help {
+            Some(help) if self.related.is_empty() => write!(f, " (help: {help})")?,
+            _ => {}
+        }
+        for (i, related) in self.related.iter().enumerate() {
+            let separator = if i == 0 { ": " } else { "; " };
+            write!(f, "{separator}{related}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<CompileError> for String {
+    fn from(err: CompileError) -> Self {
+        format!("Failed to compile program - {err}")
+    }
+}
+
+#[test]
+fn test_compile_error() {
+    let mut program = MidenProgram::new("begin\n    push.1 foo.2\nend", DEBUG_OFF);
+    let err = program.compile_program().unwrap_err();
+    assert_eq!(err.kind, CompileErrorKind::Syntax);
+    assert_eq!(
+        err.location,
+        Some(SourceLocation {
+            line: 2,
+            column: 12,
+            length: 3
+        })
+    );
+    assert!(err.help.is_some());
+
+    let mut program = MidenProgram::new("begin\n    push.1\n    exec.bar\nend", DEBUG_OFF);
+    let err = program.compile_program().unwrap_err();
+    assert_eq!(err.kind, CompileErrorKind::Semantic);
+    assert_eq!(err.related.len(), 1);
+    assert_eq!(
+        err.related[0].location,
+        Some(SourceLocation {
+            line: 3,
+            column: 10,
+            length: 3
+        })
+    );
+    assert_eq!(
+        String::from(err),
+        "Failed to compile program - syntax error: symbol undefined: no such name found in scope \
+         at line 3, column 10 (help: are you missing an import?)"
+    );
+}
@@ -0,0 +1,348 @@
+use crate::utils_input::{
+    check_sparse_merkle_tree_depth, parse_advice_map_key, parse_advice_map_value,
+    parse_stack_value, parse_word, sparse_merkle_tree_index, InputFile, MerkleData, MerkleRoot,
+    SparseMerkleTreeData,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use miden_vm::crypto::{MerkleStore, RpoDigest};
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of elements of the operand stack at the start of a program.
+const MAX_OPERAND_STACK_LEN: usize = 16;
+
+// VALIDATION ERROR
+// ================================================================================================
+
+/// An invalid value of an input file, located by its JSON path, e.g.
+/// `merkle_store[0].sparse_merkle_tree[3]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON path of the invalid value, empty if the whole file is invalid.
+    pub path: String,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+// VALIDATION
+// ================================================================================================
+
+/// Validates the content of an inputs file and returns every error found in it. Empty content is
+/// valid, as it leaves the inputs empty.
+pub fn validate_inputs(inputs: &str) -> Vec<ValidationError> {
+    if inputs.trim().is_empty() {
+        return Vec::new();
+    }
+    match deserialize_input_file(inputs) {
+        Ok(input_file) => input_file.validate(),
+        Err(e) => vec![e],
+    }
+}
+
+/// Deserializes an input file. A value of the wrong type is located by its JSON path, e.g.
+/// `merkle_store[0].sparse_merkle_tree`, while a file which is not valid JSON is located by its line
+/// and column in the message of the error.
+pub(crate) fn deserialize_input_file(inputs: &str) -> Result<InputFile, ValidationError> {
+    let value: Value =
+        serde_json::from_str(inputs).map_err(|e| ValidationError::new("", e.to_string()))?;
+
+    // the merkle data of the entries of the merkle store is flattened into the entries, which hides
+    // the path of its values, so that it is deserialized on its own first
+    let entries = value.get("merkle_store").and_then(Value::as_array);
+    for (i, entry) in entries.into_iter().flatten().enumerate() {
+        let mut data = entry.clone();
+        if let Some(data) = data.as_object_mut() {
+            data.remove("name");
+        }
+        if let Err(e) = serde_path_to_error::deserialize::<_, MerkleData>(data) {
+            let path = json_path(format!("merkle_store[{i}]"), e.path());
+            return Err(ValidationError::new(path, e.into_inner().to_string()));
+        }
+    }
+
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = json_path(String::new(), e.path());
+        ValidationError::new(path, e.into_inner().to_string())
+    })
+}
+
+/// Appends the segments of a path reported by serde to a JSON path.
+fn json_path(mut json_path: String, path: &Path) -> String {
+    for segment in path.iter() {
+        match segment {
+            Segment::Seq { index } => json_path.push_str(&format!("[{index}]")),
+            // the keys of the advice map are the only keys of the file which are not field names
+            Segment::Map { key } if json_path == "advice_map" => {
+                json_path.push_str(&format!("[{key:?}]"))
+            }
+            Segment::Map { key: name } | Segment::Enum { variant: name } => {
+                if !json_path.is_empty() {
+                    json_path.push('.');
+                }
+                json_path.push_str(name);
+            }
+            Segment::Unknown => {}
+        }
+    }
+    json_path
+}
+
+impl InputFile {
+    /// Validates every value of the input file and returns the errors found, each of them located
+    /// by the JSON path of the invalid value.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        let roots = self.validate_merkle_store(&mut errors);
+
+        let operand_stack = self.operand_stack.as_deref().unwrap_or(&[]);
+        let len = validate_stack("operand_stack", operand_stack, &roots, &mut errors);
+        if len > MAX_OPERAND_STACK_LEN {
+            errors.push(ValidationError::new(
+                "operand_stack",
+                format!(
+                    "the operand stack holds {len} elements, at most {MAX_OPERAND_STACK_LEN} are \
+                     allowed"
+                ),
+            ));
+        }
+
+        let advice_stack = self.advice_stack.as_deref().unwrap_or(&[]);
+        validate_stack("advice_stack", advice_stack, &roots, &mut errors);
+
+        for (key, values) in self.advice_map.iter().flatten() {
+            let path = format!("advice_map[{key:?}]");
+            if let Err(e) = parse_advice_map_key(key, &roots) {
+                errors.push(ValidationError::new(&path, e));
+            }
+            for (i, value) in values.iter().enumerate() {
+                if let Err(e) = parse_advice_map_value(*value) {
+                    errors.push(ValidationError::new(format!("{path}[{i}]"), e));
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// Validates the merkle data of the merkle store and returns the names of the data, which can
+    /// be referenced by the other values of the file. The roots of the returned names are not
+    /// computed.
+    fn validate_merkle_store(&self, errors: &mut Vec<ValidationError>) -> Vec<MerkleRoot> {
+        let mut roots: Vec<MerkleRoot> = Vec::new();
+        for (i, entry) in self.merkle_store.iter().flatten().enumerate() {
+            let path = format!("merkle_store[{i}]");
+
+            let name = entry.name(i);
+            if roots.iter().any(|root| root.name == name) {
+                errors.push(ValidationError::new(
+                    format!("{path}.name"),
+                    format!("duplicate merkle data name `{name}`"),
+                ));
+            }
+            roots.push(MerkleRoot {
+                name,
+                root: RpoDigest::default(),
+            });
+
+            // the merkle data is only built if its values are valid, so that an invalid value is
+            // reported once, at its own path
+            let path = format!("{path}.{}", entry.data.kind());
+            let num_errors = errors.len();
+            validate_merkle_data(&entry.data, &path, errors);
+            if errors.len() == num_errors {
+                let mut merkle_store = MerkleStore::default();
+                if let Err(e) = entry
+                    .data
+                    .add_to_store(&mut merkle_store, &mut BTreeMap::new())
+                {
+                    errors.push(ValidationError::new(path, e));
+                }
+            }
+        }
+        roots
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Validates the values of a stack and returns the number of elements they expand to.
+fn validate_stack(
+    name: &str,
+    values: &[String],
+    roots: &[MerkleRoot],
+    errors: &mut Vec<ValidationError>,
+) -> usize {
+    let mut len = 0;
+    for (i, value) in values.iter().enumerate() {
+        match parse_stack_value(value, roots) {
+            Ok(elements) => len += elements.len(),
+            Err(e) => errors.push(ValidationError::new(format!("{name}[{i}]"), e)),
+        }
+    }
+    len
+}
+
+/// Validates the values of merkle data, `path` being the path of the data.
+fn validate_merkle_data(data: &MerkleData, path: &str, errors: &mut Vec<ValidationError>) {
+    match data {
+        MerkleData::MerkleTree(leaves) | MerkleData::Mmr(leaves) => {
+            for (i, leaf) in leaves.iter().enumerate() {
+                check_word(format!("{path}[{i}]"), leaf, errors);
+            }
+        }
+        MerkleData::SparseMerkleTree(tree) => {
+            let entries_path = match tree {
+                SparseMerkleTreeData::Entries(_) => path.to_string(),
+                SparseMerkleTreeData::WithDepth { .. } => format!("{path}.entries"),
+            };
+            for (i, (_, leaf)) in tree.entries().iter().enumerate() {
+                check_word(format!("{entries_path}[{i}]"), leaf, errors);
+            }
+            validate_sparse_merkle_tree(tree, path, &entries_path, errors);
+        }
+        MerkleData::PartialMerkleTree(paths) => {
+            for (i, (_, leaf, nodes)) in paths.iter().enumerate() {
+                check_word(format!("{path}[{i}][1]"), leaf, errors);
+                for (j, node) in nodes.iter().enumerate() {
+                    check_word(format!("{path}[{i}][2][{j}]"), node, errors);
+                }
+            }
+        }
+        MerkleData::Smt(entries) => {
+            for (i, (key, value)) in entries.iter().enumerate() {
+                check_word(format!("{path}[{i}][0]"), key, errors);
+                check_word(format!("{path}[{i}][1]"), value, errors);
+            }
+        }
+    }
+}
+
+/// Validates a word given as a hex string.
+fn check_word(path: String, word: &str, errors: &mut Vec<ValidationError>) {
+    if let Err(e) = parse_word(word) {
+        errors.push(ValidationError::new(path, e));
+    }
+}
+
+/// Validates the depth and the indexes of a sparse merkle tree.
+fn validate_sparse_merkle_tree(
+    tree: &SparseMerkleTreeData,
+    path: &str,
+    entries_path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    let depth = tree.depth();
+    if let Err(e) = check_sparse_merkle_tree_depth(depth) {
+        errors.push(ValidationError::new(format!("{path}.depth"), e));
+        return;
+    }
+
+    let mut indexes = BTreeSet::new();
+    for (i, (index, _)) in tree.entries().iter().enumerate() {
+        let path = format!("{entries_path}[{i}]");
+        if let Err(e) = sparse_merkle_tree_index(depth, *index) {
+            errors.push(ValidationError::new(path, e));
+        } else if !indexes.insert(*index) {
+            errors.push(ValidationError::new(
+                path,
+                format!("duplicate values for index {index}"),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_validate_inputs_types() {
+    let errors = validate_inputs(r#"{"advice_map": {"zz": [1, "2"]}}"#);
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with(r#"advice_map["zz"][1]: invalid type: string"#),
+        "{}",
+        errors[0]
+    );
+
+    let errors = validate_inputs(r#"{"merkle_store": [{"sparse_merkle_tree": {"depth": 8}}]}"#);
+    assert_eq!(errors[0].path, "merkle_store[0].sparse_merkle_tree");
+
+    let errors = validate_inputs(r#"{"operand_stack": [1]}"#);
+    assert_eq!(errors[0].path, "operand_stack[0]");
+}
+
+#[test]
+fn test_validate_inputs() {
+    let leaf = "0100000000000000000000000000000000000000000000000000000000000000";
+    let errors = validate_inputs(&format!(
+        r#"{{
+            "operand_stack": ["1", "root:missing", "u64:1", "word:{leaf}", "word:{leaf}",
+                              "word:{leaf}", "word:{leaf}"],
+            "advice_stack": ["18446744069414584321"],
+            "advice_map": {{"zz": [1, 18446744073709551615]}},
+            "merkle_store": [
+                {{"sparse_merkle_tree": {{"depth": 8, "entries": [[1, "{leaf}"], [256, "{leaf}"]]}}}},
+                {{"merkle_tree": ["{leaf}", "{leaf}", "{leaf}"]}}
+            ]
+        }}"#
+    ));
+    let errors: Vec<String> = errors.iter().map(ValidationError::to_string).collect();
+
+    assert_eq!(errors[0], "merkle_store[0].sparse_merkle_tree.entries[1]: index 256 does not fit into a tree of depth 8");
+    assert!(
+        errors[1].starts_with("merkle_store[1].merkle_tree: "),
+        "{}",
+        errors[1]
+    );
+    assert_eq!(errors[2], "operand_stack[1]: unknown merkle data `missing`");
+    assert_eq!(
+        errors[3],
+        "operand_stack: the operand stack holds 19 elements, at most 16 are allowed"
+    );
+    assert!(
+        errors[4].starts_with("advice_stack[0]: 18446744069414584321 is not a field element"),
+        "{}",
+        errors[4]
+    );
+    assert!(
+        errors[5].starts_with(r#"advice_map["zz"]: failed to decode advice map key"#),
+        "{}",
+        errors[5]
+    );
+    assert!(
+        errors[6].starts_with(r#"advice_map["zz"][1]: "#),
+        "{}",
+        errors[6]
+    );
+    assert_eq!(errors.len(), 7);
+}
@@ -1,5 +1,11 @@
 import '@testing-library/jest-dom';
-import { checkInputs, checkOutputs } from '../src/utils/helper_functions';
+import {
+  checkInputs,
+  checkOutputs,
+  compileErrorLocations,
+  formatCompileError,
+  isCompileError
+} from '../src/utils/helper_functions';
 
 const output_example_incl_errors = `{
     "stack_output": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
@@ -30,6 +36,14 @@ const correct_input = `{
     "operand_stack": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
     "advice_stack": [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
 }`;
+const correct_input_smt_depth = `{
+    "operand_stack": [1],
+    "merkle_store": [
+        {"sparse_merkle_tree": {"depth": 16, "entries": [
+            [1, "0000000000000000000000000000000333000000000000000000000000000000"]
+        ]}}
+    ]
+}`;
 
 describe('checkInputs function', () => {
   it('should return true if inputs are empty', async () => {
@@ -59,6 +73,13 @@ an advice_stack.`
       errorMessage: ''
     });
   });
+
+  it('should return true if a sparse merkle tree has a depth', async () => {
+    expect(checkInputs(correct_input_smt_depth)).toStrictEqual({
+      isValid: true,
+      errorMessage: ''
+    });
+  });
 });
 
 /** Testing the checkOutputs function */
@@ -99,3 +120,33 @@ Did you prove the program first?`
     });
   });
 });
+
+describe('formatCompileError', () => {
+  const compileError = {
+    kind: 'semantic' as const,
+    message: 'syntax error',
+    labels: [],
+    help: 'see emitted diagnostics for details',
+    related: [
+      {
+        kind: 'semantic' as const,
+        message: 'symbol undefined: no such name found in scope',
+        location: { line: 3, column: 10, length: 3 },
+        labels: [{ location: { line: 3, column: 10, length: 3 } }],
+        help: 'are you missing an import?',
+        related: []
+      }
+    ]
+  };
+
+  it('should format the related errors with their location', async () => {
+    expect(isCompileError(compileError)).toBe(true);
+    expect(isCompileError('Failed to run program')).toBe(false);
+    expect(formatCompileError(compileError)).toBe(`syntax error
+symbol undefined: no such name found in scope (line 3, column 10)
+  help: are you missing an import?`);
+    expect(compileErrorLocations(compileError)).toStrictEqual([
+      { line: 3, column: 10, length: 3 }
+    ]);
+  });
+});
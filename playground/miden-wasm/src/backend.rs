@@ -1,26 +1,67 @@
-use crate::utils_input::Inputs;
-use crate::utils_program::MidenProgram;
-use crate::types::Outputs;
-
-use miden_stdlib::StdLibrary;
-use miden_vm::{DefaultHost, ProvingOptions, ExecutionProof};
+use miden_examples_core::{validate_inputs, CompileError, Inputs, MidenProgram, Outputs, DEBUG_OFF};
 use miden_processor::ExecutionOptions;
-use alloc::string::String;
+use miden_vm::{Digest, Kernel, ProgramInfo};
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::fmt;
+use crate::utils_proving::{
+    verify_program_proof, verify_proof, ProofParams, ProvingOptionsInput, VerificationFailure,
+    VerificationReport, MIN_SECURITY_LEVEL,
+};
+
+/// Error of the backend. Compile errors keep their structure, so that the editor can show them
+/// next to the offending code.
+#[derive(Debug)]
+pub enum BackendError {
+    Compile(CompileError),
+    Verification(VerificationFailure),
+    Other(String),
+}
+
+impl From<CompileError> for BackendError {
+    fn from(err: CompileError) -> Self {
+        Self::Compile(err)
+    }
+}
 
-const MAX_STACK_LENGTH: usize = 40;
+impl From<VerificationFailure> for BackendError {
+    fn from(err: VerificationFailure) -> Self {
+        Self::Verification(err)
+    }
+}
+
+impl From<String> for BackendError {
+    fn from(err: String) -> Self {
+        Self::Other(err)
+    }
+}
 
-pub fn run_program_native(code: &str, inputs_str: &str) -> Result<Outputs, String> {
-    let mut program = MidenProgram::new(code, crate::utils_program::DEBUG_OFF);
-    program.compile_program().map_err(|e| format!("{e:?}"))?;
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compile(err) => write!(f, "{err}"),
+            Self::Verification(err) => write!(f, "{err}"),
+            Self::Other(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Runs the program and returns its outputs. The stack output holds the complete stack unless
+/// `num_outputs` truncates it, as does the one of [prove_program_native].
+pub fn run_program_native(
+    code: &str,
+    inputs_str: &str,
+    num_outputs: Option<usize>,
+) -> Result<Outputs, BackendError> {
+    let mut program = MidenProgram::new(code, DEBUG_OFF);
+    program.compile_program()?;
 
     let mut input_data = Inputs::new();
-    input_data.deserialize_inputs(inputs_str).map_err(|e| format!("{e:?}"))?;
+    input_data.deserialize_inputs(inputs_str)?;
     let stack_inputs = input_data.stack_inputs.clone();
 
     // fetch the stack and program inputs from the arguments
-    let mut host = DefaultHost::new(input_data.advice_provider.clone());
-    host.load_mast_forest(StdLibrary::default().mast_forest().clone()).unwrap();
+    let mut host = MidenProgram::new_host(input_data.advice_provider.clone())?;
 
     let exec_options = ExecutionOptions::default();
 
@@ -30,26 +71,53 @@ pub fn run_program_native(code: &str, inputs_str: &str) -> Result<Outputs, Strin
         &mut host,
         exec_options,
     )
-    .map_err(|e| format!("{e:?}"))?;
+    .map_err(|e| e.to_string())?;
 
-    Ok(Outputs {
+    let mut outputs = Outputs {
         program_hash: trace.program_hash().to_string(),
-        stack_output: trace.stack_outputs().iter().map(|f| f.as_int()).collect(),
-        cycles: Some(trace.trace_len_summary().trace_len()),
+        stack_output: trace.stack_outputs().as_int_vec(),
+        overflow_addrs: Vec::new(),
+        cycles: Some(trace.trace_len_summary().main_trace_len()),
         trace_len: Some(trace.get_trace_len()),
         proof: None,
-    })
+        merkle_roots: input_data.merkle_roots_hex(),
+    };
+    if let Some(num_outputs) = num_outputs {
+        outputs.truncate_stack(num_outputs);
+    }
+    Ok(outputs)
 }
 
-pub fn prove_program_native(code: &str, inputs_str: &str) -> Result<Outputs, String> {
-    let mut program = MidenProgram::new(code, crate::utils_program::DEBUG_OFF);
-    program.compile_program().map_err(|e| format!("{e:?}"))?;
+/// Proves the program with the given proving options and returns its outputs together with the
+/// parameters of the proof. Unless `num_outputs` truncates the stack output, the outputs can be
+/// passed to [verify_program_native] as they are.
+pub fn prove_program_native(
+    code: &str,
+    inputs_str: &str,
+    options: &ProvingOptionsInput,
+    num_outputs: Option<usize>,
+) -> Result<(Outputs, ProofParams), BackendError> {
+    let proof_options = options.to_proving_options()?;
+
+    let mut program = MidenProgram::new(code, DEBUG_OFF);
+    program.compile_program()?;
 
     let mut inputs = Inputs::new();
-    inputs.deserialize_inputs(inputs_str).map_err(|e| format!("{e:?}"))?;
+    inputs.deserialize_inputs(inputs_str)?;
 
-    let proof_options = ProvingOptions::default();
-    let mut host = DefaultHost::new(inputs.advice_provider.clone());
+    // the prover does not report the number of cycles, so the program is executed first
+    let mut host = MidenProgram::new_host(inputs.advice_provider.clone())?;
+    let trace = miden_vm::execute(
+        program.program.as_ref().unwrap(),
+        inputs.stack_inputs.clone(),
+        &mut host,
+        ExecutionOptions::default(),
+    )
+    .map_err(|e| e.to_string())?;
+    let cycles = trace.trace_len_summary().main_trace_len();
+    drop(trace);
+
+    let mut host = MidenProgram::new_host(inputs.advice_provider.clone())?;
 
     let (output, proof) = miden_vm::prove(
         &program.program.unwrap(),
@@ -57,47 +125,95 @@ pub fn prove_program_native(code: &str, inputs_str: &str) -> Result<Outputs, Str
         &mut host,
         proof_options,
     )
-    .map_err(|e| format!("{e:?}"))?;
+    .map_err(|e| e.to_string())?;
 
-    miden_vm::verify(
+    // the proof must reach the security level selected by the user, whatever the parameters
+    verify_proof(
         program.program_info.clone().unwrap(),
-        inputs.stack_inputs,
+        inputs.stack_inputs.clone(),
         output.clone(),
         proof.clone(),
-    )
-    .map_err(|e| format!("Failed to verify proof: {e:?}"))?;
+        options.security,
+    )?;
 
-    Ok(Outputs {
+    let mut outputs = Outputs {
         program_hash: program.program_info.unwrap().program_hash().to_string(),
-        stack_output: output.stack_truncated(MAX_STACK_LENGTH).iter().map(|f| f.as_int()).collect(),
-        cycles: None,
+        stack_output: output.as_int_vec(),
+        overflow_addrs: Vec::new(),
+        cycles: Some(cycles),
         trace_len: Some(proof.stark_proof().trace_info().length()),
         proof: Some(proof.to_bytes()),
-    })
+        merkle_roots: inputs.merkle_roots_hex(),
+    };
+    if let Some(num_outputs) = num_outputs {
+        outputs.truncate_stack(num_outputs);
+    }
+    Ok((outputs, ProofParams::from_proof(&proof)))
 }
 
+/// Verifies a proof of the program against the inputs and the claimed outputs. A failed check,
+/// including a proof with less than [MIN_SECURITY_LEVEL] bits of conjectured security, is reported
+/// as [BackendError::Verification].
 pub fn verify_program_native(
     code: &str,
     inputs_str: &str,
     outputs_str: &str,
     proof: Vec<u8>,
-) -> Result<u32, String> {
-    let mut program = MidenProgram::new(code, crate::utils_program::DEBUG_OFF);
-    program.compile_program().map_err(|e| format!("{e:?}"))?;
+) -> Result<VerificationReport, BackendError> {
+    let mut program = MidenProgram::new(code, DEBUG_OFF);
+    program.compile_program()?;
 
-    let mut inputs = Inputs::new();
-    inputs.deserialize_inputs(inputs_str).map_err(|e| format!("{e:?}"))?;
-    inputs.deserialize_outputs(outputs_str).map_err(|e| format!("{e:?}"))?;
+    let (inputs, outputs) = parse_public_inputs(inputs_str, outputs_str)?;
+    Ok(verify_program_proof(program.program_info.unwrap(), &inputs, &outputs, &proof, MIN_SECURITY_LEVEL)?)
+}
 
-    let proof = ExecutionProof::from_bytes(&proof).map_err(|e| format!("{e:?}"))?;
+/// Verifies a proof against the hash of the program, given as a hex string, instead of its source,
+/// so that the proof of a third party can be verified without their code. The program is expected
+/// to be assembled without a kernel, as the programs of the playground are. As the origin of the
+/// proof is not trusted, it must reach `min_security` bits of conjectured security, which cannot
+/// be less than [MIN_SECURITY_LEVEL] and defaults to it.
+pub fn verify_by_hash_native(
+    program_hash: &str,
+    inputs_str: &str,
+    outputs_str: &str,
+    proof: Vec<u8>,
+    min_security: Option<u32>,
+) -> Result<VerificationReport, BackendError> {
+    let min_security = min_security.unwrap_or(MIN_SECURITY_LEVEL);
+    if min_security < MIN_SECURITY_LEVEL {
+        return Err(BackendError::Other(format!(
+            "minimum security level must be at least {MIN_SECURITY_LEVEL} bits, got {min_security}"
+        )));
+    }
+
+    let program_hash = Digest::try_from(program_hash).map_err(|e| {
+        VerificationFailure::InvalidPublicInputs {
+            message: format!("invalid program hash `{program_hash}` - {e}"),
+        }
+    })?;
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+
+    let (inputs, outputs) = parse_public_inputs(inputs_str, outputs_str)?;
+    Ok(verify_program_proof(program_info, &inputs, &outputs, &proof, min_security)?)
+}
 
-    miden_vm::verify(
-        program.program_info.unwrap(),
-        inputs.stack_inputs,
-        inputs.stack_outputs,
-        proof,
-    )
-    .map_err(|e| format!("Failed to verify: {e:?}"))
+/// Parses the inputs file and the claimed outputs a proof is verified against.
+fn parse_public_inputs(inputs_str: &str, outputs_str: &str) -> Result<(Inputs, Outputs), BackendError> {
+    let mut inputs = Inputs::new();
+    inputs.deserialize_inputs(inputs_str)?;
+    let outputs: Outputs = serde_json::from_str(outputs_str).map_err(|e| {
+        VerificationFailure::InvalidPublicInputs { message: format!("invalid outputs - {e}") }
+    })?;
+    Ok((inputs, outputs))
+}
+
+/// Validates the inputs and returns every invalid value together with its JSON path, e.g.
+/// `operand_stack[2]: ...`. The inputs are valid if no errors are returned.
+pub fn validate_inputs_native(inputs_str: &str) -> Vec<String> {
+    validate_inputs(inputs_str)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
 }
 
 /// Basic tests for the Rust part
@@ -110,6 +226,7 @@ fn test_run_program() {
             swap drop
         end",
         "",
+        None,
     )
     .unwrap();
     assert_eq!(
@@ -131,6 +248,7 @@ fn test_run_program_with_std_lib() {
             swap drop
         end",
         "",
+        None,
     )
     .unwrap();
     assert_eq!(
@@ -142,12 +260,14 @@ fn test_run_program_with_std_lib() {
 
 #[test]
 fn test_prove_program() {
-    let output = prove_program_native(
+    let (output, proof_params) = prove_program_native(
         "begin
             push.1 push.2 add
             swap drop
         end",
         "",
+        &ProvingOptionsInput::default(),
+        None,
     )
     .unwrap();
     // this is the result of the stack output, 3
@@ -159,6 +279,92 @@ fn test_prove_program() {
 
     // we expect a proof of []
     assert!(output.proof.is_some());
+    assert_eq!(proof_params.hash_fn, "blake3-192");
+    assert_eq!(proof_params.num_queries, 27);
+}
+
+#[test]
+fn test_prove_program_with_options() {
+    let options = ProvingOptionsInput {
+        security: 128,
+        num_queries: Some(40),
+        ..Default::default()
+    };
+    let (_, proof_params) = prove_program_native("begin push.1 drop end", "", &options, None).unwrap();
+    assert_eq!(proof_params.hash_fn, "blake3-256");
+    assert_eq!(proof_params.num_queries, 40);
+    assert_eq!(proof_params.blowup_factor, 16);
+    assert_eq!(proof_params.field_extension, "cubic");
+}
+
+#[test]
+fn test_prove_program_with_invalid_options() {
+    // a blowup factor below the minimum of the AIR is rejected instead of panicking in the prover
+    let options = ProvingOptionsInput {
+        blowup_factor: Some(4),
+        ..Default::default()
+    };
+    let err = prove_program_native("begin push.1 drop end", "", &options, None).unwrap_err();
+    assert!(matches!(err, BackendError::Other(_)));
+}
+
+#[test]
+fn test_verify_low_security_proof() {
+    use miden_vm::{FieldExtension, HashFunction, ProvingOptions};
+
+    let asm = "begin push.1 push.2 add swap drop end";
+    let outputs_str = r#"{"stack_output": [3]}"#;
+
+    // a single query gives almost no security, so the proof is rejected although it is valid
+    let mut program = MidenProgram::new(asm, DEBUG_OFF);
+    program.compile_program().unwrap();
+    let options =
+        ProvingOptions::new(1, 8, 0, FieldExtension::Quadratic, 8, 255, HashFunction::Blake3_192);
+    let (_, proof) = miden_vm::prove(
+        &program.program.unwrap(),
+        Default::default(),
+        &mut MidenProgram::new_host(Default::default()).unwrap(),
+        options,
+    )
+    .unwrap();
+    let err = verify_program_native(asm, "", outputs_str, proof.to_bytes()).unwrap_err();
+    match err {
+        BackendError::Verification(VerificationFailure::InsufficientSecurity {
+            security_level,
+            min_security,
+        }) => {
+            assert!(security_level < MIN_SECURITY_LEVEL);
+            assert_eq!(min_security, MIN_SECURITY_LEVEL);
+        }
+        err => panic!("expected insufficient security, got {err}"),
+    }
+
+    // the playground refuses to prove with options below the selected security level
+    let options = ProvingOptionsInput {
+        num_queries: Some(1),
+        ..Default::default()
+    };
+    let err = prove_program_native(asm, "", &options, None).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::InsufficientSecurity { .. })
+    ));
+
+    // the presets reach the security level they are named after
+    let options = ProvingOptionsInput {
+        security: 128,
+        ..Default::default()
+    };
+    let (_, proof_params) = prove_program_native(asm, "", &options, None).unwrap();
+    assert!(proof_params.security_level >= 128);
+}
+
+#[test]
+fn test_execution_error() {
+    // errors of the VM are reported with their message rather than their debug representation
+    let err = run_program_native("begin push.0 assert end", "", None).unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("assertion failed"), "{message}");
 }
 
 #[test]
@@ -180,11 +386,115 @@ fn test_verify_program() {
         "trace_len": 1024
     }"#;
 
-    let prove_result = prove_program_native(asm, input_str).unwrap();
+    let (prove_result, _) = prove_program_native(asm, input_str, &ProvingOptionsInput::default(), None).unwrap();
+
+    let proof = prove_result.proof.unwrap();
+    let report = verify_program_native(asm, input_str, output_str, proof.clone()).unwrap();
+
+    // the default proving options target 96-bit security with Blake3
+    assert_eq!(report.security_level, 96);
+    assert_eq!(report.program_hash, prove_result.program_hash);
+    assert_eq!(report.proof_params.hash_fn, "blake3-192");
+    assert_eq!(report.trace_len, 64);
+    // the stack inputs are padded to the 16 elements of the operand stack
+    assert_eq!(report.stack_inputs, vec![0; 16]);
+    assert_eq!(report.stack_outputs[0], 3);
+
+    // an edited output no longer matches the proof
+    let edited_output_str = r#"{"stack_output": [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]}"#;
+    let err = verify_program_native(asm, input_str, edited_output_str, proof.clone()).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::PublicInputsMismatch { .. })
+    ));
+
+    let other_program_str = r#"{"program_hash": "0x00", "stack_output": [3]}"#;
+    let err = verify_program_native(asm, input_str, other_program_str, proof).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::ProgramHashMismatch { .. })
+    ));
+
+    let err = verify_program_native(asm, input_str, output_str, vec![0, 1, 2]).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::MalformedProof { .. })
+    ));
+}
+
+#[test]
+fn test_outputs_round_trip() {
+    let asm = "begin push.1 push.2 add swap drop end";
+    let run_outputs = run_program_native(asm, "", None).unwrap();
+    let (outputs, _) = prove_program_native(asm, "", &ProvingOptionsInput::default(), None).unwrap();
+    assert_eq!(outputs.stack_output, run_outputs.stack_output);
+    assert!(outputs.overflow_addrs.is_empty());
+    // both count the cycles as the length of the main trace
+    assert_eq!(outputs.cycles, run_outputs.cycles);
+    assert_eq!(outputs.cycles, Some(8));
+
+    // the outputs of the prover are verified as they are
+    let proof = outputs.proof.clone().unwrap();
+    let outputs_str = serde_json::to_string(&outputs).unwrap();
+    verify_program_native(asm, "", &outputs_str, proof.clone()).unwrap();
+
+    // only zeros are left out of truncated outputs, so they still verify
+    let (outputs, _) = prove_program_native(asm, "", &ProvingOptionsInput::default(), Some(1)).unwrap();
+    assert_eq!(outputs.stack_output, vec![3]);
+    let outputs_str = serde_json::to_string(&outputs).unwrap();
+    verify_program_native(asm, "", &outputs_str, proof).unwrap();
+}
+
+#[test]
+fn test_verify_by_hash() {
+    let asm = "begin push.1 push.2 add swap drop end";
+    let (outputs, _) = prove_program_native(asm, "", &ProvingOptionsInput::default(), None).unwrap();
+    let proof = outputs.proof.clone().unwrap();
+    let outputs_str = serde_json::to_string(&outputs).unwrap();
+
+    // the verifier needs the program hash, but not the code
+    let report = verify_by_hash_native(&outputs.program_hash, "", &outputs_str, proof.clone(), None).unwrap();
+    assert_eq!(report.program_hash, outputs.program_hash);
+    assert_eq!(report.security_level, 96);
+    assert_eq!(report.min_security_level, MIN_SECURITY_LEVEL);
+
+    // a verifier may require more security than the proof provides, but not less than the minimum
+    let err = verify_by_hash_native(&outputs.program_hash, "", &outputs_str, proof.clone(), Some(128)).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::InsufficientSecurity { security_level: 96, min_security: 128 })
+    ));
+    let err = verify_by_hash_native(&outputs.program_hash, "", &outputs_str, proof.clone(), Some(0)).unwrap_err();
+    assert!(matches!(err, BackendError::Other(_)));
+
+    let err = verify_by_hash_native("0x1234", "", &outputs_str, proof, None).unwrap_err();
+    assert!(matches!(
+        err,
+        BackendError::Verification(VerificationFailure::InvalidPublicInputs { .. })
+    ));
+}
 
-    let result = verify_program_native(asm, input_str, output_str, prove_result.proof.unwrap()).unwrap();
+#[test]
+fn test_validate_inputs() {
+    assert!(validate_inputs_native(r#"{"operand_stack": ["1"]}"#).is_empty());
 
-    assert_eq!(result, 96);
+    let errors = validate_inputs_native(r#"{"operand_stack": ["1", "two"]}"#);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("operand_stack[1]: "));
+}
+
+#[test]
+fn test_compile_error() {
+    use miden_examples_core::CompileErrorKind;
+
+    let err = run_program_native("begin\n    push.1 foo.2\nend", "", None).unwrap_err();
+    match err {
+        BackendError::Compile(err) => {
+            assert_eq!(err.kind, CompileErrorKind::Syntax);
+            assert_eq!(err.location.map(|location| location.line), Some(2));
+        }
+        err => panic!("expected a compile error, got {err}"),
+    }
 }
 
 #[test]
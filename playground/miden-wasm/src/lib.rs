@@ -1,12 +1,11 @@
 extern crate alloc;
 
 mod utils_debug;
-mod utils_input;
-mod utils_program;
+mod utils_proving;
 mod backend;
-mod types;
 
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
 pub use backend::*;
+pub use utils_proving::{ProofHashFunction, ProofParams, ProvingOptionsInput};
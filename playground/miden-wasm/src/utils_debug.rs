@@ -1,6 +1,5 @@
-use crate::utils_input::Inputs;
-use crate::utils_program::{MidenProgram, DEBUG_ON};
-use miden_vm::{VmState, VmStateIterator, DefaultHost, MemAdviceProvider};
+use miden_examples_core::{Inputs, MidenProgram, DEBUG_ON};
+use miden_vm::{VmState, VmStateIterator};
 use wasm_bindgen::prelude::*;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -46,12 +45,12 @@ impl DebugExecutor {
     #[wasm_bindgen(constructor)]
     pub fn new(code_frontend: &str, inputs_frontend: &str) -> Result<DebugExecutor, String> {
         let mut program = MidenProgram::new(code_frontend, DEBUG_ON);
-        program.compile_program().unwrap();
+        program.compile_program()?;
 
         let mut inputs = Inputs::new();
-        inputs.deserialize_inputs(inputs_frontend).unwrap();
+        inputs.deserialize_inputs(inputs_frontend)?;
 
-        let mut host = DefaultHost::new(MemAdviceProvider::from(inputs.advice_provider));
+        let mut host = MidenProgram::new_host(inputs.advice_provider)?;
 
         let mut vm_state_iter = miden_vm::execute_iter(
             &program.program.unwrap(),
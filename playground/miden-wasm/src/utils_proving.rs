@@ -0,0 +1,343 @@
+use miden_air::{ProcessorAir, PublicInputs};
+use miden_vm::crypto::{
+    Blake3_192, Blake3_256, Rpo256, RpoRandomCoin, Rpx256, RpxRandomCoin, WinterRandomCoin,
+};
+use miden_examples_core::{Inputs, Outputs};
+use miden_vm::{ExecutionProof, FieldExtension, HashFunction, ProgramInfo, ProvingOptions, StackInputs, StackOutputs};
+use winter_verifier::{crypto::MerkleTree, AcceptableOptions, VerifierError};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+// LIMITS
+// ================================================================================================
+
+// these mirror the limits enforced by the STARK prover, which panics if they are violated
+
+const MAX_NUM_QUERIES: usize = 255;
+/// The constraints of the processor AIR need a blowup factor of at least 8.
+const MIN_BLOWUP_FACTOR: usize = 8;
+const MAX_BLOWUP_FACTOR: usize = 128;
+
+/// Conjectured security level in bits below which proofs fail verification, the one of the 96-bit
+/// preset.
+pub const MIN_SECURITY_LEVEL: u32 = 96;
+
+// PROVING OPTIONS
+// ================================================================================================
+
+/// Proving options selected in the playground. The proof parameters start from the preset of the
+/// security level and the hash function, and the number of queries and the blowup factor override
+/// the preset if they are given.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ProvingOptionsInput {
+    /// Targeted security level in bits, 96 or 128.
+    pub security: u32,
+    pub hash_fn: ProofHashFunction,
+    pub num_queries: Option<usize>,
+    pub blowup_factor: Option<usize>,
+}
+
+/// Hash function used to build the proof. RPO proofs are suited for recursive verification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProofHashFunction {
+    Blake3,
+    Rpo,
+}
+
+impl Default for ProvingOptionsInput {
+    fn default() -> Self {
+        Self {
+            security: 96,
+            hash_fn: ProofHashFunction::Blake3,
+            num_queries: None,
+            blowup_factor: None,
+        }
+    }
+}
+
+impl ProvingOptionsInput {
+    /// Returns the proving options described by these options, or an error if they are outside of
+    /// the limits of the STARK prover.
+    pub fn to_proving_options(&self) -> Result<ProvingOptions, String> {
+        // the presets of ProvingOptions::with_96_bit_security and with_128_bit_security
+        let (blowup_factor, grinding_factor, field_extension) = match self.security {
+            96 => (8, 16, FieldExtension::Quadratic),
+            128 => (16, 21, FieldExtension::Cubic),
+            security => {
+                return Err(format!("security level must be 96 or 128 bits, got {security}"))
+            }
+        };
+        let (fri_folding_factor, fri_remainder_max_degree, hash_fn) = match self.hash_fn {
+            ProofHashFunction::Rpo => (4, 7, HashFunction::Rpo256),
+            ProofHashFunction::Blake3 if self.security == 96 => (8, 255, HashFunction::Blake3_192),
+            ProofHashFunction::Blake3 => (8, 255, HashFunction::Blake3_256),
+        };
+
+        let num_queries = self.num_queries.unwrap_or(27);
+        if num_queries == 0 || num_queries > MAX_NUM_QUERIES {
+            return Err(format!(
+                "number of queries must be between 1 and {MAX_NUM_QUERIES}, got {num_queries}"
+            ));
+        }
+        let blowup_factor = self.blowup_factor.unwrap_or(blowup_factor);
+        if !blowup_factor.is_power_of_two()
+            || !(MIN_BLOWUP_FACTOR..=MAX_BLOWUP_FACTOR).contains(&blowup_factor)
+        {
+            return Err(format!(
+                "blowup factor must be a power of two between {MIN_BLOWUP_FACTOR} and \
+                 {MAX_BLOWUP_FACTOR}, got {blowup_factor}"
+            ));
+        }
+
+        Ok(ProvingOptions::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            field_extension,
+            fri_folding_factor,
+            fri_remainder_max_degree,
+            hash_fn,
+        ))
+    }
+}
+
+// PROOF PARAMETERS
+// ================================================================================================
+
+/// STARK proof parameters of a proof, as they were effectively used by the prover.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ProofParams {
+    /// Conjectured security level of the proof in bits.
+    pub security_level: u32,
+    pub hash_fn: String,
+    pub num_queries: usize,
+    pub blowup_factor: usize,
+    pub grinding_factor: u32,
+    pub field_extension: String,
+    pub fri_folding_factor: usize,
+    pub fri_remainder_max_degree: usize,
+}
+
+impl ProofParams {
+    /// Reads the proof parameters from a proof.
+    pub fn from_proof(proof: &ExecutionProof) -> Self {
+        let options = proof.stark_proof().options();
+        let fri_options = options.to_fri_options();
+        let hash_fn = match proof.hash_fn() {
+            HashFunction::Blake3_192 => "blake3-192",
+            HashFunction::Blake3_256 => "blake3-256",
+            HashFunction::Rpo256 => "rpo256",
+            HashFunction::Rpx256 => "rpx256",
+        };
+        let field_extension = match options.field_extension() {
+            FieldExtension::None => "none",
+            FieldExtension::Quadratic => "quadratic",
+            FieldExtension::Cubic => "cubic",
+        };
+        Self {
+            security_level: proof.security_level(),
+            hash_fn: hash_fn.to_string(),
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            field_extension: field_extension.to_string(),
+            fri_folding_factor: fri_options.folding_factor(),
+            fri_remainder_max_degree: fri_options.remainder_max_degree(),
+        }
+    }
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Report of a successful verification, listing what the proof was checked against.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerificationReport {
+    /// Conjectured security level of the proof in bits.
+    pub security_level: u32,
+    /// Conjectured security level in bits the proof was required to reach.
+    pub min_security_level: u32,
+    /// Hash of the program the proof was verified against.
+    pub program_hash: String,
+    /// Parameters read from the proof.
+    pub proof_params: ProofParams,
+    /// Length of the execution trace, read from the proof.
+    pub trace_len: usize,
+    /// Stack inputs bound by the proof, the top of the stack first.
+    pub stack_inputs: Vec<u64>,
+    /// Stack outputs bound by the proof, the top of the stack first.
+    pub stack_outputs: Vec<u64>,
+}
+
+/// The check which failed when verifying a proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum VerificationFailure {
+    /// The proof could not be deserialized.
+    MalformedProof { message: String },
+    /// The inputs or the outputs could not be parsed.
+    InvalidPublicInputs { message: String },
+    /// The outputs name another program than the one the proof is verified against.
+    ProgramHashMismatch { expected: String, actual: String },
+    /// The proof is valid, but for other stack inputs or outputs, e.g. the outputs were edited
+    /// after proving.
+    PublicInputsMismatch { message: String },
+    /// The proof itself is invalid.
+    InvalidProof { message: String },
+    /// The proof is valid, but its conjectured security is below the required level.
+    InsufficientSecurity { security_level: u32, min_security: u32 },
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedProof { message } => write!(f, "malformed proof - {message}"),
+            Self::InvalidPublicInputs { message } => write!(f, "invalid public inputs - {message}"),
+            Self::ProgramHashMismatch { expected, actual } => write!(
+                f,
+                "the proof was generated for program {actual}, not for program {expected}"
+            ),
+            Self::PublicInputsMismatch { message } => write!(
+                f,
+                "the proof does not match the stack inputs and outputs - {message}"
+            ),
+            Self::InvalidProof { message } => write!(f, "invalid proof - {message}"),
+            Self::InsufficientSecurity { security_level, min_security } => write!(
+                f,
+                "the proof has {security_level}-bit conjectured security, less than the required \
+                 {min_security} bits"
+            ),
+        }
+    }
+}
+
+/// Verifies a proof built with any proof parameters and returns its conjectured security level in
+/// bits. Unlike `miden_vm::verify`, which only accepts the presets of [ProvingOptions], the proof
+/// is accepted whatever its parameters as long as its conjectured security is at least
+/// `min_security` bits, so that the playground can verify the proofs of the options selected by
+/// the user.
+pub fn verify_proof(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    min_security: u32,
+) -> Result<u32, VerificationFailure> {
+    let security_level = proof.security_level();
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let options = AcceptableOptions::MinConjecturedSecurity(min_security);
+    let (hash_fn, proof) = proof.into_parts();
+    match hash_fn {
+        HashFunction::Blake3_192 => winter_verifier::verify::<
+            ProcessorAir,
+            Blake3_192,
+            WinterRandomCoin<_>,
+            MerkleTree<_>,
+        >(proof, pub_inputs, &options),
+        HashFunction::Blake3_256 => winter_verifier::verify::<
+            ProcessorAir,
+            Blake3_256,
+            WinterRandomCoin<_>,
+            MerkleTree<_>,
+        >(proof, pub_inputs, &options),
+        HashFunction::Rpo256 => {
+            winter_verifier::verify::<ProcessorAir, Rpo256, RpoRandomCoin, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+        HashFunction::Rpx256 => {
+            winter_verifier::verify::<ProcessorAir, Rpx256, RpxRandomCoin, MerkleTree<_>>(
+                proof, pub_inputs, &options,
+            )
+        }
+    }
+    .map_err(|err| {
+        let message = err.to_string();
+        match err {
+            // the public inputs are bound by the out-of-domain evaluations
+            VerifierError::InconsistentOodConstraintEvaluations => {
+                VerificationFailure::PublicInputsMismatch { message }
+            }
+            VerifierError::InsufficientConjecturedSecurity(min_security, security_level) => {
+                VerificationFailure::InsufficientSecurity { security_level, min_security }
+            }
+            VerifierError::ProofDeserializationError(_)
+            | VerifierError::InconsistentBaseField
+            | VerifierError::UnsupportedFieldExtension(_) => {
+                VerificationFailure::MalformedProof { message }
+            }
+            _ => VerificationFailure::InvalidProof { message },
+        }
+    })?;
+    Ok(security_level)
+}
+
+/// Verifies a serialized proof against the program, the inputs file and the outputs, and reports
+/// what the proof was checked against. Outputs which name a program hash must name the one of the
+/// program, and the proof must provide at least `min_security` bits of conjectured security.
+pub fn verify_program_proof(
+    program_info: ProgramInfo,
+    inputs: &Inputs,
+    outputs: &Outputs,
+    proof: &[u8],
+    min_security: u32,
+) -> Result<VerificationReport, VerificationFailure> {
+    let proof = ExecutionProof::from_bytes(proof)
+        .map_err(|err| VerificationFailure::MalformedProof { message: err.to_string() })?;
+
+    // outputs written by older versions may not name the program they belong to
+    let program_hash = program_info.program_hash().to_string();
+    if !outputs.program_hash.is_empty() && outputs.program_hash != program_hash {
+        return Err(VerificationFailure::ProgramHashMismatch {
+            expected: program_hash,
+            actual: outputs.program_hash.clone(),
+        });
+    }
+    let stack_outputs = outputs
+        .stack_outputs()
+        .map_err(|message| VerificationFailure::InvalidPublicInputs { message })?;
+
+    let report = VerificationReport {
+        security_level: 0,
+        min_security_level: min_security,
+        program_hash,
+        proof_params: ProofParams::from_proof(&proof),
+        trace_len: proof.stark_proof().trace_info().length(),
+        stack_inputs: inputs.stack_inputs.iter().map(|f| f.as_int()).collect(),
+        stack_outputs: stack_outputs.as_int_vec(),
+    };
+    let security_level =
+        verify_proof(program_info, inputs.stack_inputs.clone(), stack_outputs, proof, min_security)?;
+    Ok(VerificationReport { security_level, ..report })
+}
+
+#[test]
+fn test_proving_options() {
+    assert_eq!(
+        ProvingOptionsInput::default().to_proving_options(),
+        Ok(ProvingOptions::with_96_bit_security(false))
+    );
+
+    let options = ProvingOptionsInput {
+        security: 128,
+        hash_fn: ProofHashFunction::Rpo,
+        ..Default::default()
+    };
+    assert_eq!(
+        options.to_proving_options(),
+        Ok(ProvingOptions::with_128_bit_security(true))
+    );
+
+    let options = ProvingOptionsInput {
+        blowup_factor: Some(6),
+        ..Default::default()
+    };
+    assert!(options.to_proving_options().is_err());
+}
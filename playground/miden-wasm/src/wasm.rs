@@ -2,8 +2,9 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use wasm_bindgen::prelude::*;
-use crate::backend::{run_program_native, prove_program_native, verify_program_native};
-use crate::types::Outputs;
+use crate::backend::{run_program_native, prove_program_native, verify_program_native, verify_by_hash_native, validate_inputs_native, BackendError};
+use crate::utils_proving::{ProofParams, ProvingOptionsInput, VerificationReport};
+use miden_examples_core::Outputs;
 use serde::{Deserialize, Serialize};
 
 #[wasm_bindgen(getter_with_clone)]
@@ -11,9 +12,12 @@ use serde::{Deserialize, Serialize};
 pub struct WasmOutputs {
     pub program_hash: String,
     pub stack_output: Vec<u64>,
+    pub overflow_addrs: Vec<u64>,
     pub cycles: Option<usize>,
     pub trace_len: Option<usize>,
     pub proof: Option<Vec<u8>>,
+    pub merkle_roots: Vec<String>,
+    pub proof_params: Option<ProofParams>,
 }
 
 // optional: automatic conversion
@@ -22,30 +26,81 @@ impl From<Outputs> for WasmOutputs {
         WasmOutputs {
             program_hash: out.program_hash,
             stack_output: out.stack_output,
+            overflow_addrs: out.overflow_addrs,
             cycles: out.cycles,
             trace_len: out.trace_len,
             proof: out.proof,
+            merkle_roots: out
+                .merkle_roots
+                .into_iter()
+                .map(|(name, root)| format!("{name}: {root}"))
+                .collect(),
+            proof_params: None,
         }
     }
 }
 
+/// Converts an error of the backend into a JS value. Compile errors are returned as objects with
+/// the `kind`, `message`, `location`, `labels`, `help` and `related` errors of the diagnostic,
+/// verification failures as objects naming the failed `check`, and any other error as a string
+/// starting with `context`.
+fn to_js_error(context: &str, err: BackendError) -> JsValue {
+    match err {
+        BackendError::Compile(err) => serde_wasm_bindgen::to_value(&err)
+            .unwrap_or_else(|_| JsValue::from_str(&String::from(err))),
+        BackendError::Verification(err) => serde_wasm_bindgen::to_value(&err)
+            .unwrap_or_else(|_| JsValue::from_str(&format!("{}: {}", context, err))),
+        BackendError::Other(err) => JsValue::from_str(&format!("{}: {}", context, err)),
+    }
+}
+
+/// Runs the program. The stack output holds the complete stack, or its top `num_outputs` elements
+/// if given.
 #[wasm_bindgen]
-pub fn run_program(code: &str, inputs: &str) -> Result<WasmOutputs, JsValue> {
-    run_program_native(code, inputs)
+pub fn run_program(code: &str, inputs: &str, num_outputs: Option<usize>) -> Result<WasmOutputs, JsValue> {
+    run_program_native(code, inputs, num_outputs)
         .map(Into::into)
-        .map_err(|err| JsValue::from_str(&format!("Failed to run program: {:?}", err)))
+        .map_err(|err| to_js_error("Failed to run program", err))
 }
 
+/// Proves the program. The proving options are an optional object such as
+/// `{ security: 128, hash_fn: "rpo", num_queries: 40, blowup_factor: 16 }`, in which every field
+/// is optional, and the parameters effectively used are returned in `proof_params`. Options whose
+/// conjectured security is below the selected `security` are rejected. The stack output holds the
+/// complete stack, or its top `num_outputs` elements if given, in which case the outputs only
+/// verify if the elements left out are zeros.
 #[wasm_bindgen]
-pub fn prove_program(code: &str, inputs: &str) -> Result<WasmOutputs, JsValue> {
-    prove_program_native(code, inputs)
-        .map(Into::into)
-        .map_err(|err| JsValue::from_str(&format!("Failed to prove program: {:?}", err)))
+pub fn prove_program(code: &str, inputs: &str, options: JsValue, num_outputs: Option<usize>) -> Result<WasmOutputs, JsValue> {
+    let options: ProvingOptionsInput = if options.is_undefined() || options.is_null() {
+        ProvingOptionsInput::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)
+            .map_err(|err| JsValue::from_str(&format!("Invalid proving options: {}", err)))?
+    };
+    let (outputs, proof_params) = prove_program_native(code, inputs, &options, num_outputs)
+        .map_err(|err| to_js_error("Failed to prove program", err))?;
+    Ok(WasmOutputs {
+        proof_params: Some(proof_params),
+        ..outputs.into()
+    })
+}
+
+#[wasm_bindgen]
+pub fn verify_program(code: &str, inputs: &str, outputs: &str, proof: Vec<u8>) -> Result<VerificationReport, JsValue> {
+    verify_program_native(code, inputs, outputs, proof)
+        .map_err(|err| to_js_error("Failed to verify program", err))
+}
+
+/// Verifies a proof against the hash of the program instead of its code, e.g. the `program_hash`
+/// returned by `prove_program`. The proof must reach `min_security` bits of conjectured security,
+/// 96 bits by default, and the report holds the security level of the proof.
+#[wasm_bindgen]
+pub fn verify_by_hash(program_hash: &str, inputs: &str, outputs: &str, proof: Vec<u8>, min_security: Option<u32>) -> Result<VerificationReport, JsValue> {
+    verify_by_hash_native(program_hash, inputs, outputs, proof, min_security)
+        .map_err(|err| to_js_error("Failed to verify program", err))
 }
 
 #[wasm_bindgen]
-pub fn verify_program(code: &str, inputs: &str, outputs: &str, proof: Vec<u8>) -> Result<u32, JsValue> {
-    let result = verify_program_native(code, inputs, outputs, proof)
-        .map_err(|err| format!("Failed to generate execution trace - {:?}", err))?;
-    Ok(result.into())
+pub fn validate_inputs(inputs: &str) -> Vec<String> {
+    validate_inputs_native(inputs)
 }
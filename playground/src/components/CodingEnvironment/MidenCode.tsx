@@ -8,8 +8,10 @@ import {
 } from '@heroicons/react/20/solid';
 import { DebugCommand } from 'miden-wasm';
 import { tags as t } from '@lezer/highlight';
-import { forwardRef, useCallback, useImperativeHandle } from 'react';
+import { forwardRef, useCallback, useImperativeHandle, useMemo } from 'react';
 import { StreamLanguage } from '@codemirror/language';
+import { Decoration, EditorView } from '@codemirror/view';
+import { CompileErrorLocation } from '../../utils/helper_functions';
 import { c } from '@codemirror/legacy-modes/mode/clike'; // Import the gas mode
 
 type MidenCodeProps = {
@@ -18,6 +20,7 @@ type MidenCodeProps = {
   showDebug: boolean;
   onChange: (value: string) => void;
   executeDebug: (command: DebugCommand, params?: bigint) => void;
+  errorLocations?: CompileErrorLocation[];
 };
 
 export interface MidenCodeHandles {
@@ -54,6 +57,34 @@ const codeTheme = createTheme({
 
 const extensions = [StreamLanguage.define(c)];
 
+const compileErrorMark = Decoration.mark({ class: 'cm-compile-error' });
+
+const compileErrorTheme = EditorView.baseTheme({
+  '.cm-compile-error': {
+    textDecoration: 'underline wavy #ef4444'
+  }
+});
+
+/**
+ * Underlines the locations of a compile error. Locations which do not fit into the code, e.g.
+ * because it was edited in the meantime, are skipped.
+ */
+function compileErrorDecorations(locations: CompileErrorLocation[]) {
+  return EditorView.decorations.compute(['doc'], (state) => {
+    const ranges = locations.flatMap(({ line, column, length }) => {
+      if (line < 1 || line > state.doc.lines) {
+        return [];
+      }
+      const docLine = state.doc.line(line);
+      const from = Math.min(docLine.from + column - 1, docLine.to);
+      // a zero length location, e.g. the end of the file, underlines a single character
+      const to = Math.min(from + Math.max(length, 1), state.doc.length);
+      return from < to ? [compileErrorMark.range(from, to)] : [];
+    });
+    return Decoration.set(ranges, true);
+  });
+}
+
 const MidenCode = forwardRef<MidenCodeHandles, MidenCodeProps>((props, ref) => {
   useImperativeHandle(ref, () => ({
     downloadCode() {
@@ -76,6 +107,15 @@ const MidenCode = forwardRef<MidenCodeHandles, MidenCodeProps>((props, ref) => {
     props.onChange(value);
   }, []);
 
+  const editorExtensions = useMemo(
+    () => [
+      ...extensions,
+      compileErrorTheme,
+      compileErrorDecorations(props.errorLocations ?? [])
+    ],
+    [props.errorLocations]
+  );
+
   return (
     <div className="flex flex-col w-full font-geist-mono overflow-auto miden-code-layout">
       <div className="flex w-full py-1.5 px-2">
@@ -117,7 +157,7 @@ const MidenCode = forwardRef<MidenCodeHandles, MidenCodeProps>((props, ref) => {
       <CodeMirror
         value={props.value}
         theme={codeTheme}
-        extensions={extensions}
+        extensions={editorExtensions}
         onChange={onChange}
         basicSetup={{
           foldGutter: true,
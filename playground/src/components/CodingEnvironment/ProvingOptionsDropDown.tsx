@@ -0,0 +1,98 @@
+import { Fragment, useState } from 'react';
+import { Listbox, Transition } from '@headlessui/react';
+import { ChevronDownIcon } from '@heroicons/react/24/solid';
+import { LOCAL_STORAGE } from '../../utils/constants';
+
+export interface ProvingOptions {
+  security: number;
+  hash_fn: 'blake3' | 'rpo';
+}
+
+export const provingOptionsPresets: { label: string; options: ProvingOptions }[] = [
+  { label: '96-bit Blake3', options: { security: 96, hash_fn: 'blake3' } },
+  { label: '128-bit Blake3', options: { security: 128, hash_fn: 'blake3' } },
+  { label: '96-bit RPO', options: { security: 96, hash_fn: 'rpo' } },
+  { label: '128-bit RPO', options: { security: 128, hash_fn: 'rpo' } }
+];
+
+function classExamples(...classes: string[]) {
+  return classes.filter(Boolean).join(' ');
+}
+
+interface ProvingOptionsDropDownProps {
+  onProvingOptionsChange?: (options: ProvingOptions) => void;
+}
+
+export default function ProvingOptionsDropDown({
+  onProvingOptionsChange
+}: Readonly<ProvingOptionsDropDownProps>): JSX.Element {
+  const [selected, setSelected] = useState<string>(
+    () =>
+      localStorage.getItem(LOCAL_STORAGE.PROVING_OPTIONS) ??
+      provingOptionsPresets[0].label
+  );
+
+  return (
+    <Listbox
+      value={selected}
+      onChange={(value) => {
+        const preset = provingOptionsPresets.find(
+          (preset) => preset.label === value
+        );
+        if (preset) {
+          onProvingOptionsChange?.(preset.options);
+        }
+        setSelected(value);
+      }}
+    >
+      {({ open }) => (
+        <div className="relative flex-grow sm:flex-grow-0 sm:mb-0 ml-3">
+          <Listbox.Button className="relative hover:bg-secondary-8 cursor-pointer rounded-md border text-white border-secondary-4 bg-primary py-2 pl-3 pr-10 text-left shadow-sm focus:border-deeporange-200 focus:outline-none focus:ring-1 focus:ring-deeporange-200 sm:text-sm">
+            <span className="block truncate max-w-xs text-xs sm:max-w-none">
+              {selected}
+            </span>
+            <span className="pointer-events-none absolute inset-y-0 right-0 flex items-center pr-2">
+              <ChevronDownIcon className="h-3 w-3 stroke-accent-1 stroke-2" />
+            </span>
+          </Listbox.Button>
+          <Transition
+            show={open}
+            as={Fragment}
+            leave="transition ease-in duration-100"
+            leaveFrom="opacity-100"
+            leaveTo="opacity-0"
+          >
+            <Listbox.Options className="absolute z-10 mt-1 max-h-60 w-full overflow-auto rounded-md bg-primary py-1 text-base shadow-lg ring-1 ring-black ring-opacity-5 focus:outline-none sm:text-sm">
+              {provingOptionsPresets.map(({ label }) => (
+                <Listbox.Option
+                  key={label}
+                  className={({ active }) =>
+                    classExamples(
+                      active ? 'text-accent-1 bg-secondary-8' : 'text-white',
+                      'relative cursor-pointer select-none py-2 pl-3'
+                    )
+                  }
+                  value={label}
+                  data-testid="select-option"
+                >
+                  {({ selected }) => (
+                    <span
+                      className={classExamples(
+                        selected
+                          ? 'font-semibold text-accent-1'
+                          : 'font-normal text-white',
+                        'block truncate text-xs'
+                      )}
+                    >
+                      {label}
+                    </span>
+                  )}
+                </Listbox.Option>
+              ))}
+            </Listbox.Options>
+          </Transition>
+        </div>
+      )}
+    </Listbox>
+  );
+}
@@ -35,7 +35,13 @@ import {
   formatDebuggerOutput,
   formatMemory,
   formatBeautifyNumbersArray,
-  formatDuration
+  formatDuration,
+  formatCompileError,
+  formatVerificationFailure,
+  isCompileError,
+  isVerificationFailure,
+  compileErrorLocations,
+  CompileErrorLocation
 } from '../utils/helper_functions';
 import { PlusIcon } from '@heroicons/react/24/solid';
 import {
@@ -54,6 +60,10 @@ import {
 import { ProgramInfoInterface } from './ProgramInfo';
 import ExplainerPage from './Explainer';
 import SizeDropDown from '../components/CodingEnvironment/SizeDropDown';
+import ProvingOptionsDropDown, {
+  ProvingOptions,
+  provingOptionsPresets
+} from '../components/CodingEnvironment/ProvingOptionsDropDown';
 import OnboardingDialog from '../components/OnboardingDialog';
 import InfoSectionLayout from './InfoSectionLayout';
 
@@ -108,6 +118,43 @@ export default function CodingEnvironment(): JSX.Element {
    */
   const [proof, setProof] = useState<Uint8Array | null>(null);
 
+  /**
+   * The locations of the last compile error, which are underlined in the code.
+   */
+  const [errorLocations, setErrorLocations] = useState<
+    CompileErrorLocation[]
+  >([]);
+
+  useEffect(() => setErrorLocations([]), [code]);
+
+  /**
+   * Returns the message of an error thrown by the backend, and underlines the code of compile
+   * errors.
+   */
+  const handleBackendError = (error: unknown): string => {
+    if (isCompileError(error)) {
+      setErrorLocations(compileErrorLocations(error));
+      return formatCompileError(error);
+    }
+    if (isVerificationFailure(error)) {
+      return formatVerificationFailure(error);
+    }
+    return error instanceof Error ? error.message : String(error);
+  };
+
+  /**
+   * The proving options the program is proven with.
+   */
+  const [provingOptions, setProvingOptions] = useState<ProvingOptions>(
+    () =>
+      (
+        provingOptionsPresets.find(
+          (preset) =>
+            preset.label === localStorage.getItem(LOCAL_STORAGE.PROVING_OPTIONS)
+        ) ?? provingOptionsPresets[0]
+      ).options
+  );
+
   /**
    * Determines when to show the debug menu
    */
@@ -306,6 +353,19 @@ export default function CodingEnvironment(): JSX.Element {
     localStorage.setItem(LOCAL_STORAGE.MIDEN_CODE_SIZE, newSize.toString());
   };
 
+  const handleProvingOptionsChange = (options: ProvingOptions) => {
+    setProvingOptions(options);
+
+    const preset = provingOptionsPresets.find(
+      (preset) =>
+        preset.options.security === options.security &&
+        preset.options.hash_fn === options.hash_fn
+    );
+    if (preset) {
+      localStorage.setItem(LOCAL_STORAGE.PROVING_OPTIONS, preset.label);
+    }
+  };
+
   const hideAllRightSideLayout = () => {
     setIsProgramInfoVisible(false);
     setShowDebug(false);
@@ -489,32 +549,39 @@ export default function CodingEnvironment(): JSX.Element {
         try {
           const start = Date.now();
 
-          const { program_hash, stack_output, cycles, trace_len }: WasmOutputs =
-            run_program(code, inputs);
+          const {
+            program_hash,
+            stack_output,
+            overflow_addrs,
+            cycles,
+            trace_len,
+            merkle_roots
+          }: WasmOutputs = run_program(code, inputs);
 
           hideAllRightSideLayout();
 
           setStackOutputValue(stack_output.toString());
           setOutput(`{
             "stack_output" : [${stack_output.toString()}],
+            "overflow_addrs" : [${overflow_addrs.toString()}],
             "trace_len" : ${trace_len}
             }`);
 
           setProgramInfo({
             program_hash: program_hash,
             cycles: cycles,
-            trace_len: trace_len
+            trace_len: trace_len,
+            merkle_roots: merkle_roots
           });
           setIsProgramInfoVisible(true);
           setIsStackOutputVisible(true);
           toast.success(`Execution successful in ${Date.now() - start} ms`);
         } catch (error) {
-          const errorMessage =
-            error instanceof Error ? error.message : String(error);
+          const errorMessage = handleBackendError(error);
           hideAllRightSideLayout();
           setProgramInfo({ error: errorMessage });
           setIsProgramInfoVisible(true);
-          setOutput(`Error: ${error}`);
+          setOutput(`Error: ${errorMessage}`);
         }
       })
       .finally(() => setIsProcessing(false));
@@ -572,11 +639,12 @@ export default function CodingEnvironment(): JSX.Element {
 
         setIsProgramInfoVisible(true);
       } else {
-        setOutput(`Error: ${error}`);
+        const errorMessage = handleBackendError(error);
+        setOutput(`Error: ${errorMessage}`);
         hideAllRightSideLayout();
-        setProgramInfo({ error });
+        setProgramInfo({ error: errorMessage });
         setIsProgramInfoVisible(true);
-        toast.error(`Error: ${error}`, {
+        toast.error(`Error: ${errorMessage}`, {
           id: 'provingToast',
           duration: 3000
         });
@@ -585,8 +653,8 @@ export default function CodingEnvironment(): JSX.Element {
       setIsProcessing(false);
     };
 
-    worker.postMessage({ code, inputs });
-  }, [code, inputs]);
+    worker.postMessage({ code, inputs, provingOptions });
+  }, [code, inputs, provingOptions]);
 
   /**
    * It starts a debugging session.
@@ -640,16 +708,20 @@ export default function CodingEnvironment(): JSX.Element {
       }
       try {
         const start = Date.now();
-        const result = verify_program(code, inputs, output, proof);
+        const report = verify_program(code, inputs, output, proof);
         toast.success(
           'Verification successful in ' +
             (Date.now() - start) +
             ' ms with a security level of ' +
-            result +
-            'bits.'
+            report.security_level +
+            ' bits (' +
+            report.proof_params.hash_fn +
+            ', trace length ' +
+            report.trace_len +
+            ').'
         );
       } catch (error) {
-        setOutput(`Error: ${error}`);
+        setOutput(`Error: ${handleBackendError(error)}`);
         toast.error('Verification failed');
       }
     });
@@ -976,6 +1048,9 @@ export default function CodingEnvironment(): JSX.Element {
                   >
                     Prove
                   </button>
+                  <ProvingOptionsDropDown
+                    onProvingOptionsChange={handleProvingOptionsChange}
+                  />
                 </div>
 
                 <div className="ml-auto example-drop-down">
@@ -991,6 +1066,7 @@ export default function CodingEnvironment(): JSX.Element {
                 showDebug={showDebug}
                 onChange={setCode}
                 executeDebug={executeDebug}
+                errorLocations={errorLocations}
               />
             </div>
 
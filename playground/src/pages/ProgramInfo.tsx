@@ -4,9 +4,22 @@ export interface ProgramInfoInterface {
   program_hash?: string;
   cycles?: number;
   trace_len?: number;
+  merkle_roots?: string[];
+  proof_params?: ProofParams;
   error?: string;
 }
 
+export interface ProofParams {
+  security_level: number;
+  hash_fn: string;
+  num_queries: number;
+  blowup_factor: number;
+  grinding_factor: number;
+  field_extension: string;
+  fri_folding_factor: number;
+  fri_remainder_max_degree: number;
+}
+
 type ProgramInfoProps = {
   programInfo: ProgramInfoInterface;
 };
@@ -68,6 +81,50 @@ const ProgramInfo = (props: ProgramInfoProps): JSX.Element => {
                       </span>
                     </p>
                   </div>
+
+                  {programInfo.merkle_roots &&
+                    programInfo.merkle_roots.length > 0 && (
+                      <>
+                        <p
+                          className="pl-4 text-left text-secondary-6 text-sm font-normal mt-6"
+                          style={{ whiteSpace: 'pre-line' }}
+                        >
+                          Merkle Roots:
+                        </p>
+                        {programInfo.merkle_roots.map((merkleRoot) => (
+                          <p
+                            key={merkleRoot}
+                            className="pl-4 text-left text-white mt-2 text-sm font-normal break-words"
+                            style={{ whiteSpace: 'pre-line' }}
+                          >
+                            {merkleRoot}
+                          </p>
+                        ))}
+                      </>
+                    )}
+
+                  {programInfo.proof_params && (
+                    <>
+                      <p
+                        className="pl-4 text-left text-secondary-6 text-sm font-normal mt-6"
+                        style={{ whiteSpace: 'pre-line' }}
+                      >
+                        Proof Parameters:
+                      </p>
+                      {Object.entries(programInfo.proof_params).map(
+                        ([name, value]) => (
+                          <p
+                            key={name}
+                            className="pl-4 text-left text-secondary-6 mt-2 text-sm font-normal break-words"
+                            style={{ whiteSpace: 'pre-line' }}
+                          >
+                            {name}:
+                            <span className="text-white ml-1">{value}</span>
+                          </p>
+                        )
+                      )}
+                    </>
+                  )}
                 </>
               )}
             </>
@@ -1,7 +1,7 @@
 import init, { prove_program } from 'miden-wasm';
 
 onmessage = async function (e) {
-  const { code, inputs } = e.data;
+  const { code, inputs, provingOptions } = e.data;
 
   try {
     await init();
@@ -11,9 +11,10 @@ onmessage = async function (e) {
       stack_output,
       trace_len,
       overflow_addrs,
-      proof
-    } = prove_program(code, inputs);
-    const overflow = overflow_addrs ? overflow_addrs.toString() : '[]';
+      proof,
+      merkle_roots,
+      proof_params
+    } = prove_program(code, inputs, provingOptions);
 
     postMessage({
       success: true,
@@ -21,11 +22,22 @@ onmessage = async function (e) {
         programInfo: {
           program_hash,
           cycles,
-          trace_len
+          trace_len,
+          merkle_roots,
+          proof_params: proof_params && {
+            security_level: proof_params.security_level,
+            hash_fn: proof_params.hash_fn,
+            num_queries: proof_params.num_queries,
+            blowup_factor: proof_params.blowup_factor,
+            grinding_factor: proof_params.grinding_factor,
+            field_extension: proof_params.field_extension,
+            fri_folding_factor: proof_params.fri_folding_factor,
+            fri_remainder_max_degree: proof_params.fri_remainder_max_degree
+          }
         },
         output: `{
             "stack_output" : [${stack_output.toString()}],
-            "overflow_addrs" : [${overflow}],
+            "overflow_addrs" : [${overflow_addrs.toString()}],
             "trace_len" : ${trace_len}
           }`,
         proof,
@@ -33,7 +45,13 @@ onmessage = async function (e) {
       }
     });
   } catch (error) {
-    const errorMessage = error instanceof Error ? error.message : String(error);
+    // compile errors are plain objects which locate the error in the code, and are posted as they are
+    const errorMessage =
+      error instanceof Error
+        ? error.message
+        : typeof error === 'object' && error !== null
+          ? error
+          : String(error);
     postMessage({ success: false, error: errorMessage });
   }
 };
@@ -26,5 +26,6 @@ export const LOCAL_STORAGE = {
   ADVICE_VALUE: 'advice_value',
   OPERAND_VALUE: 'operand_value',
   INPUT_STRING: 'input_string',
-  ONBOARDING_SHOWN: 'onboarding_shown'
+  ONBOARDING_SHOWN: 'onboarding_shown',
+  PROVING_OPTIONS: 'proving_options'
 };
@@ -28,10 +28,12 @@ const adviceMapParser = yup
   .object()
   .test(
     'advice_map_keys',
-    'advice_map keys must be 64-character-long hexadecimal strings',
+    "advice_map keys must be 64-character-long hexadecimal strings or 'root:<name>' references",
     (value) => {
       if (!value) return true;
-      return Object.keys(value).every((key) => /^[0-9a-fA-F]{64}$/.test(key));
+      return Object.keys(value).every(
+        (key) => /^[0-9a-fA-F]{64}$/.test(key) || key.startsWith('root:')
+      );
     }
   )
   .test(
@@ -53,16 +55,38 @@ const integerStringArraySchema = (inputName: string) => {
     .mixed()
     .test(
       'is-integer',
-      `${inputName} must contain strings representing valid numbers.`,
+      `${inputName} must contain strings representing valid numbers or typed values ('0x...', 'u64:...', 'word:...', 'str:...', 'root:...').`,
       (value) => {
         if (typeof value === 'string') {
-          return Number.isInteger(parseInt(value ?? ''));
+          return (
+            /^(u64|word|str|root):/.test(value) ||
+            /^0x[0-9a-fA-F]+$/.test(value) ||
+            Number.isInteger(parseInt(value ?? ''))
+          );
         }
         return false;
       }
     );
 };
 
+// Schema for a word given as a 64-character hexadecimal string
+const hexWordParser = (name: string) =>
+  yup
+    .string()
+    .length(64)
+    .matches(
+      /^[0-9a-fA-F]+$/,
+      `${name} must be a 64-character hexadecimal string`
+    )
+    .required();
+
+// Schema for the entries of a sparse_merkle_tree
+const sparseMerkleTreeEntriesParser = yup
+  .array()
+  .of(
+    yup.tuple([yup.number().required(), hexWordParser('sparse_merkle_tree leaf')])
+  );
+
 // Schema for merkle_store
 const merkleStoreParser = yup
   .array()
@@ -70,41 +94,57 @@ const merkleStoreParser = yup
     yup
       .object()
       .shape({
+        name: yup.string().notRequired(),
         merkle_tree: yup
           .array()
-          .of(
-            yup
-              .string()
-              .length(64)
-              .matches(
-                /^[0-9a-fA-F]+$/,
-                'merkle_tree leaf must be a 64-character hexadecimal string'
-              )
-              .required()
-          )
+          .of(hexWordParser('merkle_tree leaf'))
           .notRequired(),
-        sparse_merkle_tree: yup
+        sparse_merkle_tree: yup.lazy((value) =>
+          Array.isArray(value) || value === undefined
+            ? sparseMerkleTreeEntriesParser.notRequired()
+            : yup
+                .object()
+                .shape({
+                  depth: yup.number().integer().min(1).max(64).required(),
+                  entries: sparseMerkleTreeEntriesParser.required()
+                })
+                .noUnknown(
+                  "sparse_merkle_tree can only contain 'depth' and 'entries'"
+                )
+        ),
+        mmr: yup.array().of(hexWordParser('mmr leaf')).notRequired(),
+        partial_merkle_tree: yup
           .array()
           .of(
             yup.tuple([
               yup.number().required(),
+              hexWordParser('partial_merkle_tree leaf'),
               yup
-                .string()
-                .length(64)
-                .matches(
-                  /^[0-9a-fA-F]+$/,
-                  'sparse_merkle_tree leaf must be a 64-character hexadecimal string'
-                )
+                .array()
+                .of(hexWordParser('partial_merkle_tree path node'))
                 .required()
             ])
           )
+          .notRequired(),
+        smt: yup
+          .array()
+          .of(
+            yup.tuple([hexWordParser('smt key'), hexWordParser('smt value')])
+          )
           .notRequired()
       })
       .test(
         'no_additional_properties',
-        "merkle_store can only contain 'merkle_tree' or 'sparse_merkle_tree' objects",
+        "merkle_store can only contain 'merkle_tree', 'sparse_merkle_tree', 'mmr', 'partial_merkle_tree' or 'smt' objects",
         (value) => {
-          const allowedProperties = ['merkle_tree', 'sparse_merkle_tree'];
+          const allowedProperties = [
+            'name',
+            'merkle_tree',
+            'sparse_merkle_tree',
+            'mmr',
+            'partial_merkle_tree',
+            'smt'
+          ];
           const objectKeys = Object.keys(value);
           return objectKeys.every((key) => allowedProperties.includes(key));
         }
@@ -264,3 +304,116 @@ export function measureSizeInKB(data: Uint8Array): number {
 export const formatDuration = (duration: number): string => {
   return duration > 1000 ? `${(duration / 1000).toFixed(1)} seconds` : `${duration} milliseconds`;
 };
+
+/**
+ * Location of a compile error in the code, as returned by the backend. Lines and columns start
+ * at 1.
+ */
+export interface CompileErrorLocation {
+  line: number;
+  column: number;
+  length: number;
+}
+
+/**
+ * Structured error returned by the backend when the program cannot be compiled.
+ */
+export interface CompileError {
+  kind: 'syntax' | 'semantic' | 'assembly';
+  message: string;
+  location?: CompileErrorLocation;
+  labels: { message?: string; location: CompileErrorLocation }[];
+  help?: string;
+  related: CompileError[];
+}
+
+export function isCompileError(error: unknown): error is CompileError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    'kind' in error &&
+    'message' in error &&
+    'related' in error
+  );
+}
+
+/**
+ * Helper function to format a compile error and its related errors, one per line.
+ */
+export function formatCompileError(error: CompileError): string {
+  const location = error.location
+    ? ` (line ${error.location.line}, column ${error.location.column})`
+    : '';
+  const lines = [`${error.message}${location}`];
+  error.labels
+    .filter((label) => label.message)
+    .forEach((label) =>
+      lines.push(
+        `  ${label.message} (line ${label.location.line}, column ${label.location.column})`
+      )
+    );
+  // the help of a batch of errors only refers to the related errors
+  if (error.help && error.related.length === 0) {
+    lines.push(`  help: ${error.help}`);
+  }
+  error.related.forEach((related) => lines.push(formatCompileError(related)));
+  return lines.join('\n');
+}
+
+/**
+ * Helper function to collect the locations of a compile error and its related errors.
+ */
+export function compileErrorLocations(
+  error: CompileError
+): CompileErrorLocation[] {
+  return [
+    ...error.labels.map((label) => label.location),
+    ...error.related.flatMap(compileErrorLocations)
+  ];
+}
+
+/**
+ * Structured error returned by the backend when a proof fails verification, naming the check
+ * which failed.
+ */
+export type VerificationFailure =
+  | {
+      check:
+        | 'malformed_proof'
+        | 'invalid_public_inputs'
+        | 'public_inputs_mismatch'
+        | 'invalid_proof';
+      message: string;
+    }
+  | { check: 'program_hash_mismatch'; expected: string; actual: string }
+  | {
+      check: 'insufficient_security';
+      security_level: number;
+      min_security: number;
+    };
+
+export function isVerificationFailure(
+  error: unknown
+): error is VerificationFailure {
+  return typeof error === 'object' && error !== null && 'check' in error;
+}
+
+/**
+ * Helper function to describe the failed check of a verification.
+ */
+export function formatVerificationFailure(error: VerificationFailure): string {
+  switch (error.check) {
+    case 'program_hash_mismatch':
+      return `The proof was generated for program ${error.actual}, not for program ${error.expected}`;
+    case 'malformed_proof':
+      return `The proof is malformed - ${error.message}`;
+    case 'invalid_public_inputs':
+      return `The inputs or outputs are invalid - ${error.message}`;
+    case 'public_inputs_mismatch':
+      return `The proof does not match the inputs and outputs, were they edited after proving? - ${error.message}`;
+    case 'invalid_proof':
+      return `The proof is invalid - ${error.message}`;
+    case 'insufficient_security':
+      return `The proof has ${error.security_level}-bit conjectured security, less than the required ${error.min_security} bits`;
+  }
+}